@@ -0,0 +1,46 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use rng::Rng;
+use std::hint::black_box;
+
+/// calibration lines made mostly of the letters in the digit words, so there
+/// are plenty of partial and overlapping words like `eightwone`, with a digit
+/// somewhere in each
+fn calibration() -> String {
+    const LETTERS: &[u8] = b"onetwhrfuivsxgn";
+
+    let mut input = String::new();
+    let mut rng = Rng::new(0);
+
+    for _ in 0..5000 {
+        let len = rng.range(8, 39);
+        let digit = rng.range(0, len - 1);
+        for idx in 0..len {
+            if idx == digit {
+                input.push((b'0' + rng.range(0, 9) as u8) as char);
+            } else {
+                input.push(*rng.pick(LETTERS) as char);
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn bench(c: &mut Criterion) {
+    let input = calibration();
+
+    let mut group = c.benchmark_group("calibration");
+
+    group.bench_function("matcher", |b| {
+        b.iter(|| aoc2023_d01::part2(black_box(&input)))
+    });
+
+    group.bench_function("find", |b| {
+        b.iter(|| aoc2023_d01::part2_find(black_box(&input)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,226 @@
+//! shows how each line gets read: the first and last token marked in the
+//! line, whether each was a digit or a word, and the value they make. lines
+//! where part 1 and part 2 come up with different values are flagged, since
+//! those are the ones a word changed the answer on.
+//!
+//! ```text
+//!    1 !  29  [two]1[nine]  two word, nine word; part 1 reads 11
+//!    2    42  [4]nineeightseven[2]  4 digit, 2 digit
+//!    3 !  18  [oneight]  one word, eight word; part 1 finds no digits
+//! ```
+
+use std::fmt::Write;
+use std::ops::Range;
+
+use crate::decimal;
+use crate::matcher::{Match, Matcher};
+use crate::vocabulary::Vocabulary;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    Digit,
+    Word,
+}
+
+/// a token read off a line
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    /// byte offset the token starts at
+    pub start: usize,
+    pub value: u32,
+    pub source: Source,
+}
+
+impl<'a> Token<'a> {
+    fn new(line: &'a str, found: Match) -> Token<'a> {
+        let text = &line[found.start..found.start + found.len];
+        let mut chars = text.chars();
+        let digit = chars.next().and_then(decimal::value).is_some() && chars.next().is_none();
+
+        Token {
+            text,
+            start: found.start,
+            value: found.value,
+            source: match digit {
+                true => Source::Digit,
+                false => Source::Word,
+            },
+        }
+    }
+
+    fn span(&self) -> Range<usize> {
+        self.start..self.start + self.text.len()
+    }
+}
+
+/// the first and last token on a line, which can be the same one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reading<'a> {
+    pub first: Token<'a>,
+    pub last: Token<'a>,
+}
+
+impl Reading<'_> {
+    pub fn value(&self) -> u32 {
+        self.first.value * 10 + self.last.value
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// counting from 1
+    pub number: usize,
+    pub text: &'a str,
+    /// `None` when there's nothing to read
+    pub part1: Option<Reading<'a>>,
+    pub part2: Option<Reading<'a>>,
+}
+
+impl Line<'_> {
+    pub fn disagrees(&self) -> bool {
+        self.part1.map(|reading| reading.value()) != self.part2.map(|reading| reading.value())
+    }
+}
+
+/// how part 1 reads a line: digits only, a char at a time
+fn digits(line: &str) -> Option<Reading<'_>> {
+    let mut digits = line.char_indices().filter_map(|(start, c)| {
+        let value = decimal::value(c)?;
+        Some(Token::new(
+            line,
+            Match {
+                start,
+                len: c.len_utf8(),
+                value,
+            },
+        ))
+    });
+
+    let first = digits.next()?;
+    let last = digits.next_back().unwrap_or(first);
+    Some(Reading { first, last })
+}
+
+/// every line of the input, read both ways. unlike `parse`, this doesn't stop
+/// at blank lines or lines without digits, since those are worth seeing too
+pub fn lines<'a>(binding: &'a str, words: &'a Matcher) -> impl Iterator<Item = Line<'a>> + 'a {
+    binding.lines().enumerate().map(move |(idx, text)| Line {
+        number: idx + 1,
+        text,
+        part1: digits(text),
+        part2: words
+            .first_last(text.as_bytes())
+            .map(|(first, last)| Reading {
+                first: Token::new(text, first),
+                last: Token::new(text, last),
+            }),
+    })
+}
+
+const FIRST: &str = "\x1b[1;32m";
+const LAST: &str = "\x1b[1;36m";
+const FLAG: &str = "\x1b[1;31m";
+const RESET: &str = "\x1b[0m";
+
+/// the line with part 2's tokens marked, in colour or between brackets. when
+/// the two tokens overlap, like `eightwo`, brackets go around both at once,
+/// while colour still shows where the second one takes over
+fn highlight(line: &str, reading: &Reading, colour: bool) -> String {
+    let (first, last) = (reading.first.span(), reading.last.span());
+
+    // the last token ends where the first does only when it's the same token
+    let mut spans = vec![(first.clone(), FIRST)];
+    if last.end > first.end {
+        spans.push((first.end.max(last.start)..last.end, LAST));
+    }
+    if !colour && last.start < first.end {
+        spans = vec![(first.start..last.end, FIRST)];
+    }
+
+    let mut out = String::new();
+    let mut at = 0;
+    for (span, style) in spans {
+        out += &line[at..span.start];
+        match colour {
+            true => write!(out, "{}{}{}", style, &line[span.clone()], RESET).unwrap(),
+            false => write!(out, "[{}]", &line[span.clone()]).unwrap(),
+        }
+        at = span.end;
+    }
+    out += &line[at..];
+    out
+}
+
+fn describe(token: &Token) -> String {
+    let source = match token.source {
+        Source::Digit => "digit",
+        Source::Word => "word",
+    };
+    format!("{} {}", token.text, source)
+}
+
+/// one line of the annotation
+fn render(line: &Line, colour: bool) -> String {
+    let flag = match (line.disagrees(), colour) {
+        (true, true) => format!("{}!{}", FLAG, RESET),
+        (true, false) => "!".to_string(),
+        (false, _) => " ".to_string(),
+    };
+
+    let Some(reading) = line.part2 else {
+        return match line.text.is_empty() {
+            true => format!("{:>4} {}  --  blank", line.number, flag),
+            false => format!("{:>4} {}  --  {}  no digits", line.number, flag, line.text),
+        };
+    };
+
+    let mut out = format!(
+        "{:>4} {}  {:>2}  {}  {}",
+        line.number,
+        flag,
+        reading.value(),
+        highlight(line.text, &reading, colour),
+        describe(&reading.first)
+    );
+    if reading.last != reading.first {
+        out += &format!(", {}", describe(&reading.last));
+    }
+
+    if line.disagrees() {
+        match line.part1 {
+            Some(part1) => out += &format!("; part 1 reads {}", part1.value()),
+            None => out += "; part 1 finds no digits",
+        }
+    }
+    out
+}
+
+/// every line annotated, then each part's total over the lines it can read
+/// and how many lines the two disagree on
+pub fn annotate(binding: &str, vocabulary: &Vocabulary, colour: bool) -> String {
+    let words = vocabulary.matcher();
+
+    let mut out = String::new();
+    let (mut part1, mut part2, mut disagree) = (0_u64, 0_u64, 0);
+    for line in lines(binding, &words) {
+        part1 += line.part1.map_or(0, |reading| reading.value() as u64);
+        part2 += line.part2.map_or(0, |reading| reading.value() as u64);
+        disagree += line.disagrees() as usize;
+
+        out += &render(&line, colour);
+        out.push('\n');
+    }
+
+    let lines = match disagree {
+        1 => "line disagrees",
+        _ => "lines disagree",
+    };
+    writeln!(
+        out,
+        "part 1: {}, part 2: {}, {} {}",
+        part1, part2, disagree, lines
+    )
+    .unwrap();
+    out
+}
@@ -0,0 +1,34 @@
+//! decimal digits from any script, not just `0` to `9`. unicode puts every
+//! decimal digit (general category `Nd`) in a run of ten going from zero to
+//! nine, and promises to keep it that way, so all it takes to read one is
+//! where each run starts.
+//!
+//! this is narrower than `char::is_numeric`, which also takes things like `½`
+//! and `Ⅻ` that aren't a single digit.
+
+/// the zero of every run of decimal digits, in order, as of unicode 14.0.0
+const ZEROS: [u32; 66] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E950, 0x1FBF0,
+];
+
+/// the value of `c` if it's a decimal digit in any script
+pub fn value(c: char) -> Option<u32> {
+    let c = c as u32;
+    // the last run starting at or before `c`
+    let run = ZEROS.partition_point(|&zero| zero <= c).checked_sub(1)?;
+    let value = c - ZEROS[run];
+
+    (value < 10).then_some(value)
+}
+
+/// every decimal digit and its value
+pub fn all() -> impl Iterator<Item = (char, u32)> {
+    ZEROS
+        .iter()
+        .flat_map(|&zero| (0..10).map(move |value| (char::from_u32(zero + value).unwrap(), value)))
+}
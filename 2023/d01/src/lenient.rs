@@ -0,0 +1,89 @@
+//! both parts without giving up on bad input. the regular solvers stop at the
+//! first blank line and refuse a line with no digits on it; these skip either
+//! kind, keep going to the end, and hand back the sum of every line that did
+//! read along with a note of each one that didn't.
+
+use std::fmt;
+use std::io::{self, BufRead};
+
+use scan::LineReader;
+
+use crate::matcher::Matcher;
+use crate::vocabulary::Vocabulary;
+use crate::{calibration, calibration_words, has_digits};
+
+/// why a line was left out of the sum
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Skip {
+    Blank,
+    NoDigits,
+}
+
+impl fmt::Display for Skip {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Skip::Blank => write!(f, "blank"),
+            Skip::NoDigits => write!(f, "no digits"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    /// the sum over every line that had a digit
+    pub sum: u64,
+    /// how many lines there were, skipped ones included
+    pub lines: usize,
+    /// each skipped line's number, counting from 1, and why it was skipped
+    pub skipped: Vec<(usize, Skip)>,
+}
+
+/// one line per skipped line, then a count, e.g.
+///
+/// ```text
+/// line 3: blank
+/// line 7: no digits
+/// skipped 2 of 10 lines
+/// ```
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (line, skip) in &self.skipped {
+            writeln!(f, "line {}: {}", line, skip)?;
+        }
+        writeln!(f, "skipped {} of {} lines", self.skipped.len(), self.lines)
+    }
+}
+
+fn report(
+    reader: impl BufRead,
+    words: Option<&Matcher>,
+    calibration: impl Fn(&str) -> usize,
+) -> io::Result<Report> {
+    let mut lines = LineReader::new(reader);
+    let mut report = Report::default();
+
+    while let Some((idx, line)) = lines.next_line()? {
+        report.lines += 1;
+
+        if line.is_empty() {
+            report.skipped.push((idx + 1, Skip::Blank));
+        } else if !has_digits(line, words) {
+            report.skipped.push((idx + 1, Skip::NoDigits));
+        } else {
+            report.sum += calibration(line) as u64;
+        }
+    }
+
+    Ok(report)
+}
+
+/// part 1 over every line from `reader`. for a string, pass `as_bytes()`
+pub fn part1(reader: impl BufRead) -> io::Result<Report> {
+    report(reader, None, calibration)
+}
+
+/// part 2 over every line from `reader`, reading digits from `vocabulary`
+pub fn part2(reader: impl BufRead, vocabulary: &Vocabulary) -> io::Result<Report> {
+    let words = vocabulary.matcher();
+    report(reader, Some(&words), |line| calibration_words(&words, line))
+}
@@ -0,0 +1,219 @@
+use std::io::BufRead;
+
+use scan::{LineReader, ParseError, StreamError};
+
+pub mod annotate;
+pub mod decimal;
+pub mod lenient;
+pub mod matcher;
+pub mod vocabulary;
+
+use matcher::Matcher;
+use vocabulary::Vocabulary;
+
+const PATTERNS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// whether there's a digit on the line, in any script, which can be spelled
+/// out if there's a matcher for `words`
+fn has_digits(line: &str, words: Option<&Matcher>) -> bool {
+    match words {
+        Some(matcher) => matcher.matches(line.as_bytes()).next().is_some(),
+        None => line.chars().any(|c| decimal::value(c).is_some()),
+    }
+}
+
+/// every line needs at least one digit to read
+fn check(idx: usize, line: &str, words: Option<&Matcher>) -> Result<(), ParseError> {
+    if !has_digits(line, words) {
+        return Err(ParseError::new(idx, "no digits on this line"));
+    }
+
+    Ok(())
+}
+
+/// the calibration lines, up to the first blank one. with `words` set, digits
+/// can be spelled out in english
+pub fn parse(binding: &str, words: bool) -> Result<Vec<&str>, ParseError> {
+    lines(binding, words.then(matcher::digits))
+}
+
+fn lines<'a>(binding: &'a str, words: Option<&Matcher>) -> Result<Vec<&'a str>, ParseError> {
+    let mut lines = Vec::new();
+
+    for (idx, line) in binding.split('\n').enumerate() {
+        if line.is_empty() {
+            break;
+        }
+
+        check(idx, line, words)?;
+        lines.push(line);
+    }
+
+    Ok(lines)
+}
+
+/// sums `calibration` over every line from `reader`, up to the first blank
+/// one, without holding more than a line in memory
+fn stream(
+    reader: impl BufRead,
+    words: Option<&Matcher>,
+    calibration: impl Fn(&str) -> usize,
+) -> Result<u64, StreamError> {
+    let mut lines = LineReader::new(reader);
+    let mut sum = 0;
+
+    while let Some((idx, line)) = lines.next_line()? {
+        if line.is_empty() {
+            break;
+        }
+
+        check(idx, line, words)?;
+        sum += calibration(line) as u64;
+    }
+
+    Ok(sum)
+}
+
+/// the number made from the first and last digit on a line. this goes by
+/// chars rather than bytes, so `٣` counts as a 3 the same as `3` does
+fn calibration(line: &str) -> usize {
+    let mut digits = line.chars().filter_map(decimal::value);
+
+    let first = digits.next().unwrap();
+    let last = digits.next_back().unwrap_or(first);
+
+    (first * 10 + last) as usize
+}
+
+/// the same, but with any token `words` knows about counting too
+fn calibration_words(words: &Matcher, line: &str) -> usize {
+    match words.first_last(line.as_bytes()) {
+        Some((first, last)) => (first.value * 10 + last.value) as usize,
+        None => 0,
+    }
+}
+
+/// `calibration_words` the way it was first written, searching the line from
+/// both ends for every word and then for a digit: 20 scans a line instead of 1
+fn calibration_words_find(line: &str) -> usize {
+    let patterns = PATTERNS;
+
+    let mut curr_find_idx = line.len();
+    let mut tens: usize = 0;
+    let mut ones: usize = 0;
+
+    let mut curr_rfind_idx = 0;
+
+    for i in 0..patterns.len() + 1 {
+        if i == patterns.len() {
+            let find_idx = line.find(|c: char| decimal::value(c).is_some());
+            let rfind_idx = line.rfind(|c: char| decimal::value(c).is_some());
+
+            // byte offsets, so the digit is the first char from there on
+            if let Some(find_idx) = find_idx {
+                if find_idx <= curr_find_idx {
+                    curr_find_idx = find_idx;
+                    let str_val = line[curr_find_idx..].chars().next().unwrap();
+                    tens = decimal::value(str_val).unwrap() as usize * 10;
+                }
+            }
+
+            if let Some(rfind_idx) = rfind_idx {
+                if rfind_idx >= curr_rfind_idx {
+                    curr_rfind_idx = rfind_idx;
+                    let str_val = line[curr_rfind_idx..].chars().next().unwrap();
+                    ones = decimal::value(str_val).unwrap() as usize;
+                }
+            }
+
+            break;
+        }
+
+        let find_idx = line.find(patterns[i]);
+        let rfind_idx = line.rfind(patterns[i]);
+
+        if let Some(find_idx) = find_idx {
+            if find_idx <= curr_find_idx {
+                curr_find_idx = find_idx;
+                tens = (i + 1) * 10;
+            }
+        }
+
+        if let Some(rfind_idx) = rfind_idx {
+            if rfind_idx >= curr_rfind_idx {
+                curr_rfind_idx = rfind_idx;
+                ones = i + 1;
+            }
+        }
+    }
+
+    tens + ones
+}
+
+pub fn part1(binding: &str) -> u64 {
+    let input = parse(binding, false).unwrap();
+
+    let mut sum = 0;
+
+    for line in input {
+        sum += calibration(line) as u64;
+    }
+
+    sum
+}
+
+pub fn part2(binding: &str) -> u64 {
+    sum_words(binding, matcher::digits())
+}
+
+/// part 2, reading digits from the given vocabulary instead of english
+pub fn part2_with(binding: &str, vocabulary: &Vocabulary) -> u64 {
+    sum_words(binding, &vocabulary.matcher())
+}
+
+fn sum_words(binding: &str, words: &Matcher) -> u64 {
+    let input = lines(binding, Some(words)).unwrap();
+
+    let mut sum = 0;
+
+    for line in input {
+        sum += calibration_words(words, line) as u64;
+    }
+
+    sum
+}
+
+/// part 2 with `calibration_words_find`, kept around to benchmark against
+pub fn part2_find(binding: &str) -> u64 {
+    let input = parse(binding, true).unwrap();
+
+    let mut sum = 0;
+
+    for line in input {
+        sum += calibration_words_find(line) as u64;
+    }
+
+    sum
+}
+
+/// part 1 a line at a time, for inputs too big to read in one go
+pub fn part1_stream(reader: impl BufRead) -> Result<u64, StreamError> {
+    stream(reader, None, calibration)
+}
+
+/// part 2 a line at a time, for inputs too big to read in one go
+pub fn part2_stream(reader: impl BufRead) -> Result<u64, StreamError> {
+    let words = matcher::digits();
+    stream(reader, Some(words), |line| calibration_words(words, line))
+}
+
+/// `part2_with` a line at a time
+pub fn part2_stream_with(
+    reader: impl BufRead,
+    vocabulary: &Vocabulary,
+) -> Result<u64, StreamError> {
+    let words = vocabulary.matcher();
+    stream(reader, Some(&words), |line| calibration_words(&words, line))
+}
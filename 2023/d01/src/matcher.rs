@@ -0,0 +1,154 @@
+//! finds the first and last digit on a line in a single pass, whether it's
+//! written as a digit or spelled out. it's an aho-corasick automaton over the
+//! bytes of the line: one state per prefix of a token, and a transition for
+//! every byte from every state, so scanning never backtracks and overlapping
+//! tokens like `eightwo` and `oneight` are all seen.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::OnceLock;
+
+use crate::vocabulary::{Error, Vocabulary};
+
+/// states are stored as `u16`s, root included
+pub const MAX_STATES: usize = u16::MAX as usize + 1;
+
+pub struct Matcher {
+    /// `next[state][byte]` is the state after reading `byte`. state 0 is the
+    /// root, where nothing has matched yet
+    next: Vec<[u16; 256]>,
+    /// every token that ends on reaching a state, as `(length in bytes, value)`
+    out: Vec<Vec<(usize, u32)>>,
+}
+
+/// a token found on a line
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    /// byte offset the token starts at
+    pub start: usize,
+    pub len: usize,
+    pub value: u32,
+}
+
+/// how many states the automaton for `tokens` needs: the root, and one for
+/// every distinct prefix of a token
+pub fn states<'a>(tokens: impl IntoIterator<Item = &'a str>) -> usize {
+    let mut prefixes = HashSet::new();
+    for token in tokens {
+        let bytes = token.as_bytes();
+        prefixes.extend((1..=bytes.len()).map(|len| &bytes[..len]));
+    }
+    prefixes.len() + 1
+}
+
+impl Matcher {
+    /// builds the automaton for a set of `(token, value)` pairs. tokens can't
+    /// be empty, and can only have `MAX_STATES` prefixes between them
+    pub fn new<'a>(tokens: impl IntoIterator<Item = (&'a str, u32)>) -> Result<Matcher, Error> {
+        let tokens = tokens.into_iter().collect::<Vec<(&str, u32)>>();
+        if tokens.iter().any(|(token, _)| token.is_empty()) {
+            return Err(Error::Empty);
+        }
+        let states = states(tokens.iter().map(|&(token, _)| token));
+        if states > MAX_STATES {
+            return Err(Error::States(states));
+        }
+
+        // first the trie, where an edge of 0 means there isn't one yet. the
+        // root is never a child, so that can't be confused with a real edge
+        let mut next = vec![[0_u16; 256]];
+        let mut out = vec![Vec::new()];
+
+        for (token, value) in tokens {
+            let mut state = 0;
+            for &byte in token.as_bytes() {
+                if next[state][byte as usize] == 0 {
+                    next.push([0; 256]);
+                    out.push(Vec::new());
+                    next[state][byte as usize] = (next.len() - 1) as u16;
+                }
+                state = next[state][byte as usize] as usize;
+            }
+            out[state].push((token.len(), value));
+        }
+
+        // then the failure links, breadth first so each state's link (which
+        // is always shallower) is finished before the state itself. every
+        // missing edge is filled in with where the failure link would lead,
+        // which turns the trie into a dfa
+        let mut fail = vec![0; next.len()];
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(state) = queue.pop_front() {
+            let fallback = match state {
+                0 => [0; 256],
+                _ => next[fail[state]],
+            };
+
+            for (edge, fallback) in next[state].iter_mut().zip(fallback) {
+                let child = *edge as usize;
+                if child == 0 {
+                    *edge = fallback;
+                    continue;
+                }
+
+                fail[child] = fallback as usize;
+                // a token that's a suffix of this one ends here too
+                let inherited = out[fail[child]].clone();
+                out[child].extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        Ok(Matcher { next, out })
+    }
+
+    /// every token on the line, in the order they end
+    pub fn matches<'a>(&'a self, line: &'a [u8]) -> impl Iterator<Item = Match> + 'a {
+        line.iter()
+            .scan(0, |state, &byte| {
+                *state = self.next[*state][byte as usize] as usize;
+                Some(*state)
+            })
+            .enumerate()
+            .flat_map(move |(end, state)| {
+                self.out[state].iter().map(move |&(len, value)| Match {
+                    start: end + 1 - len,
+                    len,
+                    value,
+                })
+            })
+    }
+
+    /// the first token on the line by where it starts and the last by where
+    /// it ends, or `None` if there aren't any. when two start (or end) in the
+    /// same place, the longer one wins, so `VIII` is 8 from either end rather
+    /// than `V` or `I`
+    pub fn first_last(&self, line: &[u8]) -> Option<(Match, Match)> {
+        let mut first: Option<Match> = None;
+        let mut last: Option<Match> = None;
+
+        for found in self.matches(line) {
+            if first.is_none_or(|first| {
+                found.start < first.start || (found.start == first.start && found.len > first.len)
+            }) {
+                first = Some(found);
+            }
+            let end = found.start + found.len;
+            if last.is_none_or(|last| {
+                let last_end = last.start + last.len;
+                end > last_end || (end == last_end && found.len > last.len)
+            }) {
+                last = Some(found);
+            }
+        }
+
+        Some((first?, last?))
+    }
+}
+
+/// the automaton for the default vocabulary, built the first time it's needed
+pub fn digits() -> &'static Matcher {
+    static DIGITS: OnceLock<Matcher> = OnceLock::new();
+
+    DIGITS.get_or_init(|| Vocabulary::default().matcher())
+}
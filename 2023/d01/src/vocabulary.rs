@@ -0,0 +1,167 @@
+//! the tokens part 2 reads as digits. the plain digits always count, in any
+//! script (see `decimal`). on top of them goes a set of words: the english
+//! `one` to `nine`, unless something else is asked for.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::decimal;
+use crate::matcher::{self, Matcher};
+
+/// the words from the puzzle
+pub const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// `I` to `IX`. numerals overlap each other (`VIII` holds `VII`, `III`,
+/// `V`...), but the longest one always wins, so they read the way you'd expect
+pub const ROMAN: [(&str, u32); 9] = [
+    ("I", 1),
+    ("II", 2),
+    ("III", 3),
+    ("IV", 4),
+    ("V", 5),
+    ("VI", 6),
+    ("VII", 7),
+    ("VIII", 8),
+    ("IX", 9),
+];
+
+/// every vocabulary that can be asked for by name
+pub const PRESETS: [(&str, &[(&str, u32)]); 2] = [("english", &ENGLISH), ("roman", &ROMAN)];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    Empty,
+    /// the same token was given two different values
+    Conflict(String, u32, u32),
+    /// values have to be a single digit, since two of them make a number
+    Value(String, u32),
+    UnknownPreset(String),
+    /// the tokens have more prefixes between them than the matcher has
+    /// states for
+    States(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Empty => write!(f, "a vocabulary can't have an empty token"),
+            Error::Conflict(token, a, b) => {
+                write!(f, "`{}` can't mean both {} and {}", token, a, b)
+            }
+            Error::Value(token, value) => {
+                write!(f, "`{}` is {}, but values have to be 0 to 9", token, value)
+            }
+            Error::UnknownPreset(name) => write!(
+                f,
+                "there's no vocabulary called `{}`; try one of {}",
+                name,
+                PRESETS.map(|(name, _)| name).join(", ")
+            ),
+            Error::States(states) => write!(
+                f,
+                "the tokens need {} matcher states, and there's only room for {}",
+                states,
+                matcher::MAX_STATES
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// a validated set of tokens and the digit each one stands for
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vocabulary {
+    tokens: BTreeMap<String, u32>,
+}
+
+impl Default for Vocabulary {
+    fn default() -> Vocabulary {
+        Vocabulary::preset("english").unwrap()
+    }
+}
+
+impl Vocabulary {
+    /// just the plain digits, every decimal digit unicode knows about
+    pub fn digits() -> Vocabulary {
+        Vocabulary {
+            tokens: decimal::all()
+                .map(|(digit, value)| (digit.to_string(), value))
+                .collect(),
+        }
+    }
+
+    /// the plain digits and the given words. it's an error for a token to be
+    /// empty, to stand for anything but a single digit, or to be given two
+    /// different values (including a plain digit standing for another one)
+    pub fn new<S: AsRef<str>>(
+        words: impl IntoIterator<Item = (S, u32)>,
+    ) -> Result<Vocabulary, Error> {
+        let mut vocabulary = Vocabulary::digits();
+        vocabulary.extend(words)?;
+        Ok(vocabulary)
+    }
+
+    /// the plain digits and one of the `PRESETS`
+    pub fn preset(name: &str) -> Result<Vocabulary, Error> {
+        match PRESETS.iter().find(|(preset, _)| *preset == name) {
+            Some((_, words)) => Vocabulary::new(words.iter().copied()),
+            None => Err(Error::UnknownPreset(name.to_string())),
+        }
+    }
+
+    /// adds more words, with the same checks as `new`. a word that's already
+    /// there with the same value is fine, so presets can be combined. on an
+    /// error, none of the words are added
+    pub fn extend<S: AsRef<str>>(
+        &mut self,
+        words: impl IntoIterator<Item = (S, u32)>,
+    ) -> Result<(), Error> {
+        let mut tokens = self.tokens.clone();
+        for (token, value) in words {
+            let token = token.as_ref();
+            if token.is_empty() {
+                return Err(Error::Empty);
+            }
+            if value > 9 {
+                return Err(Error::Value(token.to_string(), value));
+            }
+
+            match tokens.insert(token.to_string(), value) {
+                Some(old) if old != value => {
+                    return Err(Error::Conflict(token.to_string(), old, value))
+                }
+                _ => {}
+            }
+        }
+
+        let states = matcher::states(tokens.keys().map(String::as_str));
+        if states > matcher::MAX_STATES {
+            return Err(Error::States(states));
+        }
+
+        self.tokens = tokens;
+        Ok(())
+    }
+
+    pub fn tokens(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.tokens
+            .iter()
+            .map(|(token, value)| (token.as_str(), *value))
+    }
+
+    /// `extend` has already checked everything `Matcher::new` does
+    pub fn matcher(&self) -> Matcher {
+        Matcher::new(self.tokens()).expect("a vocabulary always fits in a matcher")
+    }
+}
@@ -0,0 +1,63 @@
+//! lenient mode's report: what it sums, what it skips, and how it says so
+
+use aoc2023_d01::lenient::{part1, part2, Report, Skip};
+use aoc2023_d01::vocabulary::Vocabulary;
+
+/// a digit line, one with none, a blank, one with only a word, then a digit
+/// line without a newline on the end
+const MIXED_UP: &str = "a1b\nxyz\n\nfour\n2";
+
+#[test]
+fn lines_without_digits() {
+    let report = part1(MIXED_UP.as_bytes()).unwrap();
+    assert_eq!(
+        report,
+        Report {
+            sum: 11 + 22,
+            lines: 5,
+            skipped: vec![(2, Skip::NoDigits), (3, Skip::Blank), (4, Skip::NoDigits)],
+        }
+    );
+    assert_eq!(
+        report.to_string(),
+        "line 2: no digits\nline 3: blank\nline 4: no digits\nskipped 3 of 5 lines\n"
+    );
+
+    // part 2 reads `four`, so only the line with nothing at all is skipped
+    let report = part2(MIXED_UP.as_bytes(), &Vocabulary::default()).unwrap();
+    assert_eq!(report.sum, 11 + 44 + 22);
+    assert_eq!(
+        report.to_string(),
+        "line 2: no digits\nline 3: blank\nskipped 2 of 5 lines\n"
+    );
+}
+
+#[test]
+fn words_and_digits() {
+    let lines = "two1nine\nabcone2threexyz\nxtwone3four\n7pqrstsixteen\nzoneight234\n";
+
+    let report = part2(lines.as_bytes(), &Vocabulary::default()).unwrap();
+    assert_eq!(report.sum, 29 + 13 + 24 + 76 + 14);
+    assert_eq!(report.to_string(), "skipped 0 of 5 lines\n");
+
+    // part 1 only sees the digits, which every line has
+    let report = part1(lines.as_bytes()).unwrap();
+    assert_eq!(report.sum, 11 + 22 + 33 + 77 + 24);
+    assert!(report.skipped.is_empty());
+}
+
+#[test]
+fn other_vocabularies() {
+    // `IV` is a word in roman but not in english
+    let lines = "xIVx\n3VIII\n";
+
+    let report = part2(lines.as_bytes(), &Vocabulary::preset("roman").unwrap()).unwrap();
+    assert_eq!(report.sum, 44 + 38);
+
+    let report = part2(lines.as_bytes(), &Vocabulary::default()).unwrap();
+    assert_eq!(report.sum, 33);
+    assert_eq!(
+        report.to_string(),
+        "line 1: no digits\nskipped 1 of 2 lines\n"
+    );
+}
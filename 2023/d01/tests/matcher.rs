@@ -0,0 +1,69 @@
+//! words that share letters, which a matcher that skips past a match (or
+//! replaces it) gets wrong
+
+use aoc2023_d01::matcher::{Match, Matcher};
+use aoc2023_d01::part2;
+use aoc2023_d01::vocabulary::{Vocabulary, ENGLISH};
+
+fn english() -> Matcher {
+    Matcher::new(ENGLISH).unwrap()
+}
+
+/// every match on a line, as `(start, value)`
+fn found(line: &str) -> Vec<(usize, u32)> {
+    english()
+        .matches(line.as_bytes())
+        .map(|found| (found.start, found.value))
+        .collect()
+}
+
+#[test]
+fn overlapping_words() {
+    assert_eq!(found("eightwo"), [(0, 8), (4, 2)]);
+    assert_eq!(found("oneight"), [(0, 1), (2, 8)]);
+    assert_eq!(found("twone"), [(0, 2), (2, 1)]);
+    // and a chain of them, each sharing a letter with the next
+    assert_eq!(found("twoneight"), [(0, 2), (2, 1), (4, 8)]);
+}
+
+#[test]
+fn first_and_last() {
+    let first_last = |line: &str| {
+        let (first, last) = english().first_last(line.as_bytes()).unwrap();
+        (first.value, last.value)
+    };
+    assert_eq!(first_last("eightwo"), (8, 2));
+    assert_eq!(first_last("oneight"), (1, 8));
+    assert_eq!(first_last("twone"), (2, 1));
+
+    // a word alone is both ends
+    assert_eq!(
+        english().first_last(b"xsevenx"),
+        Some((
+            Match {
+                start: 1,
+                len: 5,
+                value: 7
+            },
+            Match {
+                start: 1,
+                len: 5,
+                value: 7
+            }
+        ))
+    );
+    assert_eq!(english().first_last(b"on ein"), None);
+}
+
+#[test]
+fn in_part2() {
+    assert_eq!(part2("eightwo\n"), 82);
+    assert_eq!(part2("oneight\n"), 18);
+    assert_eq!(part2("twone\n"), 21);
+    assert_eq!(part2("3twone\n"), 31);
+
+    // the same with digits in the vocabulary
+    let line = "xtwonex".as_bytes();
+    let (first, last) = Vocabulary::default().matcher().first_last(line).unwrap();
+    assert_eq!((first.value, last.value), (2, 1));
+}
@@ -0,0 +1,59 @@
+//! lines with characters that take more than one byte, which is where mixing
+//! up byte offsets and char indices goes wrong, and digits from scripts other
+//! than ascii.
+
+use aoc2023_d01::{decimal, parse, part1, part1_stream, part2, part2_find, part2_stream};
+
+#[test]
+fn multibyte_before_digits() {
+    // `é` and `ü` are two bytes each, so a byte offset read as a char index
+    // lands on the wrong character
+    assert_eq!(part1("é1b2ü\n"), 12);
+    assert_eq!(part1("日本語7語\n"), 77);
+    assert_eq!(part1("🎄4🎄🎄9🎄\n"), 49);
+}
+
+#[test]
+fn other_scripts() {
+    // arabic-indic three, fullwidth five, devanagari eight
+    assert_eq!(part1("a٣b7\n"), 37);
+    assert_eq!(part1("x５y\n"), 55);
+    assert_eq!(part1("८ and 2\n"), 82);
+}
+
+#[test]
+fn numerals_that_arent_digits() {
+    // `½` and `Ⅻ` are numeric but not a single decimal digit, so they're
+    // skipped rather than breaking the parse
+    assert_eq!(part1("½3Ⅻ\n"), 33);
+    assert!(parse("½Ⅻ\n", false).is_err());
+}
+
+#[test]
+fn words_and_other_scripts() {
+    let input = "éninex٤\n日本one語\n１twoñ\n";
+
+    assert_eq!(part2(input), 94 + 11 + 12);
+    assert_eq!(part2_find(input), part2(input));
+}
+
+#[test]
+fn stream_matches() {
+    let input = "é1b2ü\na٣b7\n🎄4🎄🎄9🎄\nx５ninë\n";
+
+    assert_eq!(part1_stream(input.as_bytes()).unwrap(), part1(input));
+    assert_eq!(part2_stream(input.as_bytes()).unwrap(), part2(input));
+}
+
+#[test]
+fn every_digit() {
+    for (digit, value) in decimal::all() {
+        assert_eq!(decimal::value(digit), Some(value));
+        assert!(digit.is_numeric());
+    }
+
+    assert_eq!(decimal::all().count(), 660);
+    assert_eq!(decimal::value('a'), None);
+    assert_eq!(decimal::value('½'), None);
+    assert_eq!(decimal::value('\u{10FFFF}'), None);
+}
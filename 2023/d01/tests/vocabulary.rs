@@ -0,0 +1,77 @@
+//! what a vocabulary won't take, and what it says about it
+
+use aoc2023_d01::matcher::{self, Matcher};
+use aoc2023_d01::vocabulary::{Error, Vocabulary};
+
+#[test]
+fn duplicates() {
+    // the same word twice is fine as long as it means the same thing
+    assert!(Vocabulary::new([("one", 1), ("one", 1)]).is_ok());
+
+    let err = Vocabulary::new([("one", 1), ("one", 2)]).unwrap_err();
+    assert_eq!(err, Error::Conflict("one".to_string(), 1, 2));
+    assert_eq!(err.to_string(), "`one` can't mean both 1 and 2");
+
+    // and the plain digits are already taken
+    assert_eq!(
+        Vocabulary::new([("7", 1)]),
+        Err(Error::Conflict("7".to_string(), 7, 1))
+    );
+}
+
+#[test]
+fn empty_words() {
+    let err = Vocabulary::new([("one", 1), ("", 0)]).unwrap_err();
+    assert_eq!(err, Error::Empty);
+    assert_eq!(err.to_string(), "a vocabulary can't have an empty token");
+
+    assert_eq!(Matcher::new([("", 1)]).err(), Some(Error::Empty));
+}
+
+#[test]
+fn values() {
+    assert_eq!(
+        Vocabulary::new([("ten", 10)]).unwrap_err().to_string(),
+        "`ten` is 10, but values have to be 0 to 9"
+    );
+}
+
+/// `n` words of 3 letters and then 10 of the same, so that none of them
+/// share anything past their first 3 letters
+fn long_words(n: u32) -> Vec<(String, u32)> {
+    (0..n)
+        .map(|i| {
+            let letter = |place: u32| char::from(b'a' + (i / 26_u32.pow(place) % 26) as u8);
+            let word = format!("{}{}{}{}", letter(2), letter(1), letter(0), "s".repeat(10));
+            (word, i % 10)
+        })
+        .collect()
+}
+
+#[test]
+fn overflowing_the_states() {
+    let words = long_words(7000);
+    let states = matcher::states(words.iter().map(|(word, _)| word.as_str()));
+    assert!(states > matcher::MAX_STATES, "{}", states);
+
+    let mut vocabulary = Vocabulary::default();
+    let err = vocabulary.extend(words.clone()).unwrap_err();
+    assert!(matches!(err, Error::States(n) if n > states), "{:?}", err);
+    assert!(err.to_string().ends_with("there's only room for 65536"));
+
+    // nothing was added, so the vocabulary still works as it did
+    assert_eq!(vocabulary, Vocabulary::default());
+    assert_eq!(aoc2023_d01::part2_with("xoneightx\n", &vocabulary), 18);
+
+    // and the matcher checks before it builds anything
+    let tokens = words.iter().map(|(word, value)| (word.as_str(), *value));
+    assert_eq!(Matcher::new(tokens).err(), Some(Error::States(states)));
+
+    // a few thousand fewer fit. `bcd` is word 731
+    let mut vocabulary = Vocabulary::default();
+    vocabulary.extend(long_words(5000)).unwrap();
+    assert_eq!(
+        aoc2023_d01::part2_with("xbcdssssssssss7\n", &vocabulary),
+        17
+    );
+}
@@ -0,0 +1,154 @@
+//! what's in the bag: a limit for each colour. the colours can be anything,
+//! not just the puzzle's red, green and blue, and a bag can be written the
+//! same way the games write a round:
+//!
+//! ```text
+//! 12 red, 13 green, 14 blue
+//! ```
+//!
+//! with commas or newlines between colours, so it can live in its own file.
+
+use std::fmt;
+
+use scan::ParseError;
+
+use crate::game::{parse_draw, Game};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// the same colour was given two limits
+    Duplicate(String),
+    /// a colour name has to be a single word
+    Name(String),
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Duplicate(colour) => write!(f, "the bag lists {} twice", colour),
+            Error::Name(colour) => write!(f, "`{}` isn't a colour name", colour),
+            Error::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Error {
+        Error::Parse(err)
+    }
+}
+
+/// whether `name` could be a colour in the input: a single word, with none of
+/// the punctuation that separates draws and rounds
+pub fn is_colour(name: &str) -> bool {
+    !name.is_empty() && !name.contains(|c: char| c.is_whitespace() || ",;:".contains(c))
+}
+
+/// written the way `Bag::parse` reads it, like `12 red, 13 green, 14 blue`
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let limits = self
+            .limits
+            .iter()
+            .map(|(colour, limit)| format!("{} {}", limit, colour))
+            .collect::<Vec<String>>();
+        write!(f, "{}", limits.join(", "))
+    }
+}
+
+/// each colour and the most cubes of it the bag holds, in the order given
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bag {
+    limits: Vec<(String, u32)>,
+}
+
+impl Default for Bag {
+    /// the puzzle's bag
+    fn default() -> Bag {
+        Bag::new(crate::BAG).unwrap()
+    }
+}
+
+impl Bag {
+    pub fn new<S: AsRef<str>>(limits: impl IntoIterator<Item = (S, u32)>) -> Result<Bag, Error> {
+        let mut bag = Bag { limits: Vec::new() };
+
+        for (colour, limit) in limits {
+            let colour = colour.as_ref();
+            if !is_colour(colour) {
+                return Err(Error::Name(colour.to_string()));
+            }
+            if bag.limit(colour).is_some() {
+                return Err(Error::Duplicate(colour.to_string()));
+            }
+            bag.limits.push((colour.to_string(), limit));
+        }
+
+        Ok(bag)
+    }
+
+    /// reads a bag written like `12 red, 13 green, 14 blue`, with commas or
+    /// newlines between colours. errors give the line of `spec` they're on
+    pub fn parse(spec: &str) -> Result<Bag, Error> {
+        let mut limits = Vec::new();
+
+        for (idx, line) in spec.lines().enumerate() {
+            for draw in line.split(',') {
+                let draw = draw.trim();
+                if !draw.is_empty() {
+                    let draw = parse_draw(idx, draw)?;
+                    limits.push((draw.colour, draw.count));
+                }
+            }
+        }
+
+        Bag::new(limits)
+    }
+
+    /// the most cubes of `colour` the bag holds, or `None` if it doesn't
+    /// mention `colour`
+    pub fn limit(&self, colour: &str) -> Option<u32> {
+        self.limits
+            .iter()
+            .find(|(name, _)| name == colour)
+            .map(|&(_, limit)| limit)
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.limits.iter().map(|(colour, _)| colour.as_str())
+    }
+
+    pub fn limits(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.limits
+            .iter()
+            .map(|(colour, limit)| (colour.as_str(), *limit))
+    }
+
+    /// how many cubes the bag holds, of every colour
+    pub fn total(&self) -> u64 {
+        self.limits.iter().map(|&(_, limit)| limit as u64).sum()
+    }
+
+    /// strict mode: every colour the game draws has to be one the bag knows
+    /// about. `idx` is the game's line in the input, for the error
+    pub fn check(&self, idx: usize, game: &Game) -> Result<(), ParseError> {
+        let unknown = game
+            .colours()
+            .into_iter()
+            .find(|colour| self.limit(colour).is_none());
+        match unknown {
+            Some(colour) => Err(ParseError::new(
+                idx,
+                format!(
+                    "unknown colour `{}`; the bag only has {}",
+                    colour,
+                    self.colours().collect::<Vec<&str>>().join(", ")
+                ),
+            )),
+            None => Ok(()),
+        }
+    }
+}
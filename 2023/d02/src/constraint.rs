@@ -0,0 +1,238 @@
+//! rules a game has to follow to be possible. the bag's limits are the
+//! puzzle's rules, but there are others it can't say on its own:
+//!
+//! ```text
+//! red <= 12      at most 12 red cubes in any one round
+//! round <= 20    at most 20 cubes of any colour in any one round
+//! game <= 100    at most 100 cubes drawn over the whole game
+//! round <= bag   no round draws more cubes than the bag holds in total
+//! ```
+//!
+//! `bag` stands for the bag's limit for a colour, or its total for a round.
+//! rules are separated by commas or newlines.
+
+use std::fmt;
+
+use scan::ParseError;
+
+use crate::bag::{is_colour, Bag};
+use crate::game::{Game, Round};
+
+/// what a constraint counts
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Scope {
+    /// cubes of one colour in a round
+    Colour(String),
+    /// cubes of every colour in a round
+    Round,
+    /// cubes of every colour over every round
+    Game,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constraint {
+    pub scope: Scope,
+    pub max: u64,
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.scope {
+            Scope::Colour(colour) => write!(f, "{} <= {}", colour, self.max),
+            Scope::Round => write!(f, "round <= {}", self.max),
+            Scope::Game => write!(f, "game <= {}", self.max),
+        }
+    }
+}
+
+/// a constraint a game broke, and where
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation<'c> {
+    pub constraint: &'c Constraint,
+    /// the first round that broke it, counting from 1, or `None` for a
+    /// constraint on the whole game
+    pub round: Option<usize>,
+    /// how many cubes it counted there
+    pub found: u64,
+}
+
+impl fmt::Display for Violation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let what = match &self.constraint.scope {
+            Scope::Colour(colour) => colour.as_str(),
+            Scope::Round | Scope::Game => "cubes",
+        };
+        match self.round {
+            Some(round) => write!(
+                f,
+                "{} ({} {} in round {})",
+                self.constraint, self.found, what, round
+            ),
+            None => write!(f, "{} ({} {} in all)", self.constraint, self.found, what),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Constraints {
+    pub rules: Vec<Constraint>,
+}
+
+impl Constraints {
+    /// one constraint per colour, the same rules `Game::possible` checks
+    pub fn from_bag(bag: &Bag) -> Constraints {
+        Constraints {
+            rules: bag
+                .limits()
+                .map(|(colour, max)| Constraint {
+                    scope: Scope::Colour(colour.to_string()),
+                    max: max as u64,
+                })
+                .collect(),
+        }
+    }
+
+    /// reads rules like `round <= 20, game <= bag`. `bag` is what `bag` in a
+    /// rule refers to. errors give the line of `spec` they're on
+    pub fn parse(spec: &str, bag: &Bag) -> Result<Constraints, ParseError> {
+        let mut rules = Vec::new();
+
+        for (idx, line) in spec.lines().enumerate() {
+            for rule in line.split(',').map(str::trim) {
+                if !rule.is_empty() {
+                    rules.push(parse_rule(idx, rule, bag)?);
+                }
+            }
+        }
+
+        Ok(Constraints { rules })
+    }
+
+    pub fn extend(&mut self, other: Constraints) {
+        self.rules.extend(other.rules);
+    }
+
+    /// every constraint `game` breaks, in the order they were given
+    pub fn violations(&self, game: &Game) -> Vec<Violation<'_>> {
+        self.rules
+            .iter()
+            .filter_map(|constraint| violation(constraint, game))
+            .collect()
+    }
+
+    pub fn allows(&self, game: &Game) -> bool {
+        self.rules
+            .iter()
+            .all(|constraint| violation(constraint, game).is_none())
+    }
+
+    /// each impossible game and the constraints it breaks, then a count, e.g.
+    ///
+    /// ```text
+    /// game 3: red <= 12 (20 red in round 1), round <= 20 (23 cubes in round 1)
+    /// 1 of 5 games is impossible
+    /// ```
+    pub fn report(&self, games: &[Game]) -> String {
+        let mut out = String::new();
+        let mut impossible = 0;
+
+        for game in games {
+            let violations = self.violations(game);
+            if violations.is_empty() {
+                continue;
+            }
+
+            impossible += 1;
+            let violations = violations
+                .iter()
+                .map(Violation::to_string)
+                .collect::<Vec<String>>();
+            out += &format!("game {}: {}\n", game.id, violations.join(", "));
+        }
+
+        let verb = match impossible {
+            1 => "is",
+            _ => "are",
+        };
+        out += &format!(
+            "{} of {} games {} impossible\n",
+            impossible,
+            games.len(),
+            verb
+        );
+        out
+    }
+}
+
+fn parse_rule(idx: usize, rule: &str, bag: &Bag) -> Result<Constraint, ParseError> {
+    let Some((subject, max)) = rule.split_once("<=") else {
+        return Err(ParseError::new(
+            idx,
+            format!("`{}` should look like `<what> <= <limit>`", rule),
+        ));
+    };
+    let (subject, max) = (subject.trim(), max.trim());
+
+    let scope = match subject {
+        "round" => Scope::Round,
+        "game" => Scope::Game,
+        colour if is_colour(colour) => Scope::Colour(colour.to_string()),
+        _ => {
+            return Err(ParseError::new(
+                idx,
+                format!(
+                    "can't constrain `{}`: expected a colour, `round` or `game`",
+                    subject
+                ),
+            ))
+        }
+    };
+
+    let max = match (max, &scope) {
+        ("bag", Scope::Colour(colour)) => match bag.limit(colour) {
+            Some(limit) => limit as u64,
+            None => {
+                return Err(ParseError::new(
+                    idx,
+                    format!("the bag has no limit for {}", colour),
+                ))
+            }
+        },
+        ("bag", Scope::Round) => bag.total(),
+        ("bag", Scope::Game) => {
+            return Err(ParseError::new(
+                idx,
+                "`bag` is a limit for a colour or a round, not a whole game",
+            ))
+        }
+        (max, _) => max.parse::<u64>().map_err(|_| {
+            ParseError::new(idx, format!("expected a number or `bag`, found `{}`", max))
+        })?,
+    };
+
+    Ok(Constraint { scope, max })
+}
+
+fn violation<'c>(constraint: &'c Constraint, game: &Game) -> Option<Violation<'c>> {
+    let broken = |round, found| {
+        (found > constraint.max).then_some(Violation {
+            constraint,
+            round,
+            found,
+        })
+    };
+
+    let colour = match &constraint.scope {
+        Scope::Game => return broken(None, game.rounds.iter().map(Round::total).sum()),
+        Scope::Colour(colour) => Some(colour),
+        Scope::Round => None,
+    };
+
+    game.rounds.iter().enumerate().find_map(|(idx, round)| {
+        let found = match colour {
+            Some(colour) => round.count(colour) as u64,
+            None => round.total(),
+        };
+        broken(Some(idx + 1), found)
+    })
+}
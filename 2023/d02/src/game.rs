@@ -0,0 +1,212 @@
+//! games as the puzzle writes them:
+//!
+//! ```text
+//! Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+//! ```
+//!
+//! a game is an id and its rounds in order, split by `;`. a round is the
+//! draws made before the cubes go back in the bag, split by `,`, and a draw is
+//! a count and a colour, which can be any single word. anything else on the
+//! line is an error rather than something to guess around.
+
+use std::fmt;
+
+use scan::ParseError;
+
+use crate::bag::{is_colour, Bag};
+
+/// `count` cubes of one colour, drawn at once
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Draw<'a> {
+    pub count: u32,
+    pub colour: &'a str,
+}
+
+/// `3 blue`
+impl fmt::Display for Draw<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.count, self.colour)
+    }
+}
+
+/// everything drawn before the cubes go back in the bag. each colour shows up
+/// at most once, in the order the input lists them
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Round<'a> {
+    pub draws: Vec<Draw<'a>>,
+}
+
+impl Round<'_> {
+    /// how many cubes of `colour` were drawn, which is 0 if it wasn't
+    pub fn count(&self, colour: &str) -> u32 {
+        self.draws
+            .iter()
+            .find(|draw| draw.colour == colour)
+            .map_or(0, |draw| draw.count)
+    }
+
+    /// how many cubes were drawn, of every colour
+    pub fn total(&self) -> u64 {
+        self.draws.iter().map(|draw| draw.count as u64).sum()
+    }
+}
+
+/// `3 blue, 4 red`
+impl fmt::Display for Round<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let draws = self
+            .draws
+            .iter()
+            .map(Draw::to_string)
+            .collect::<Vec<String>>();
+        write!(f, "{}", draws.join(", "))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game<'a> {
+    pub id: u32,
+    pub rounds: Vec<Round<'a>>,
+}
+
+/// the game as the input writes it, `Game 1: 3 blue, 4 red; 1 red`
+impl fmt::Display for Game<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rounds = self
+            .rounds
+            .iter()
+            .map(Round::to_string)
+            .collect::<Vec<String>>();
+        write!(f, "Game {}: {}", self.id, rounds.join("; "))
+    }
+}
+
+impl<'a> Game<'a> {
+    /// every colour drawn in the game, once each, in the order they first
+    /// show up
+    pub fn colours(&self) -> Vec<&'a str> {
+        let mut colours = Vec::new();
+        for draw in self.rounds.iter().flat_map(|round| &round.draws) {
+            if !colours.contains(&draw.colour) {
+                colours.push(draw.colour);
+            }
+        }
+        colours
+    }
+
+    /// the most cubes of `colour` drawn in any one round, which is also the
+    /// fewest the bag could have held
+    pub fn most(&self, colour: &str) -> u32 {
+        self.rounds
+            .iter()
+            .map(|round| round.count(colour))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// whether every round could have come out of the bag. colours the bag
+    /// doesn't mention aren't limited
+    pub fn possible(&self, bag: &Bag) -> bool {
+        bag.limits()
+            .all(|(colour, limit)| self.most(colour) <= limit)
+    }
+
+    /// the fewest cubes that make the game possible, multiplied together
+    /// over every colour there is: the bag's, and any others the game draws.
+    /// a colour the game never draws needs none, which makes the power 0
+    pub fn power(&self, bag: &Bag) -> u64 {
+        let colours = self.colours();
+        let others = colours.iter().filter(|colour| bag.limit(colour).is_none());
+
+        bag.colours()
+            .chain(others.copied())
+            .map(|colour| self.most(colour) as u64)
+            .product()
+    }
+}
+
+/// `text` has to be a number and nothing else, so it has to be the only
+/// thing `scan` finds in it, covering all of it
+fn parse_count(idx: usize, text: &str, what: &str) -> Result<u32, ParseError> {
+    let mut spans = scan::ints::<u32>(text).spans();
+    match (spans.next(), spans.next()) {
+        (Some((span, Ok(count))), None) if span == (0..text.len()) => Ok(count),
+        (Some((span, Err(_))), None) if span == (0..text.len()) => Err(ParseError::new(
+            idx,
+            format!("{} `{}` is too big", what, text),
+        )),
+        _ => Err(ParseError::new(
+            idx,
+            format!("expected a {}, found `{}`", what, text),
+        )),
+    }
+}
+
+/// a single `<count> <colour>`, already trimmed
+pub(crate) fn parse_draw(idx: usize, draw: &str) -> Result<Draw<'_>, ParseError> {
+    let Some((count, colour)) = draw.split_once(' ') else {
+        return Err(ParseError::new(
+            idx,
+            format!("`{}` should be a count and a colour", draw),
+        ));
+    };
+    let count = parse_count(idx, count, "count")?;
+    if !is_colour(colour) {
+        return Err(ParseError::new(
+            idx,
+            format!("`{}` isn't a colour name", colour),
+        ));
+    }
+
+    Ok(Draw { count, colour })
+}
+
+fn parse_round(idx: usize, number: usize, round: &str) -> Result<Round<'_>, ParseError> {
+    let mut draws: Vec<Draw> = Vec::new();
+
+    for draw in round.split(',') {
+        let draw = draw.trim();
+        if draw.is_empty() {
+            return Err(ParseError::new(
+                idx,
+                format!("empty draw in round {}", number),
+            ));
+        }
+
+        let draw = parse_draw(idx, draw)?;
+        if draws.iter().any(|other| other.colour == draw.colour) {
+            return Err(ParseError::new(
+                idx,
+                format!("{} is drawn twice in round {}", draw.colour, number),
+            ));
+        }
+        draws.push(draw);
+    }
+
+    Ok(Round { draws })
+}
+
+/// reads a single `Game <id>: <rounds>` line. `idx` is its index in the
+/// input, for errors
+pub fn parse_game(idx: usize, line: &str) -> Result<Game<'_>, ParseError> {
+    let line = line.trim_end();
+
+    let Some((header, rounds)) = line.split_once(':') else {
+        return Err(ParseError::new(idx, "missing `:` after the game id"));
+    };
+    let Some(id) = header.strip_prefix("Game ") else {
+        return Err(ParseError::new(
+            idx,
+            format!("expected `Game <id>`, found `{}`", header),
+        ));
+    };
+    let id = parse_count(idx, id, "game id")?;
+
+    let rounds = rounds
+        .split(';')
+        .enumerate()
+        .map(|(number, round)| parse_round(idx, number + 1, round))
+        .collect::<Result<Vec<Round>, ParseError>>()?;
+
+    Ok(Game { id, rounds })
+}
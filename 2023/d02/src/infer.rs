@@ -0,0 +1,165 @@
+//! questions about the bag, asked of every game at once rather than one game
+//! at a time. each game needs a certain number of each colour to be possible
+//! (the same minimum part 2 multiplies together), and a bag makes it possible
+//! exactly when it holds at least that many of every colour.
+//!
+//! finding the smallest bag for `k` games means picking which `k` games to
+//! cover, so it's a search. the useful limits for a colour are only ever 0 or
+//! what some game needs, so this tries every combination of those for all but
+//! the last colour, keeps the games they cover, and takes the `k`th smallest
+//! need of the last colour. that's exact, but the number of combinations is
+//! the product of the distinct counts of every colour but the last, so it
+//! grows exponentially with the palette. puzzle inputs only have three
+//! colours and a few dozen counts; past [`LIMIT`] combinations the search
+//! gives up with [`TooBig`] rather than running for ages.
+
+use std::fmt;
+
+use crate::bag::Bag;
+use crate::game::Game;
+
+/// the most combinations of limits the search will try. every bag it finds
+/// is also checked against every other, so this bounds that too
+pub const LIMIT: usize = 10_000;
+
+/// the games have too many distinct counts between them to search every
+/// combination of limits, which [`LIMIT`] caps
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TooBig {
+    pub colours: usize,
+}
+
+impl fmt::Display for TooBig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the games' {} colours have too many distinct counts between them: \
+             finding the bag would mean trying more than {} combinations",
+            self.colours, LIMIT
+        )
+    }
+}
+
+impl std::error::Error for TooBig {}
+
+/// every colour drawn in any game, in the order they first show up
+pub fn palette<'a>(games: &[Game<'a>]) -> Vec<&'a str> {
+    let mut colours = Vec::new();
+    for colour in games.iter().flat_map(Game::colours) {
+        if !colours.contains(&colour) {
+            colours.push(colour);
+        }
+    }
+    colours
+}
+
+/// each game's minimum bag, as a count for each colour of the palette
+fn needs(games: &[Game], colours: &[&str]) -> Vec<Vec<u32>> {
+    games
+        .iter()
+        .map(|game| colours.iter().map(|colour| game.most(colour)).collect())
+        .collect()
+}
+
+/// for every combination of limits on all but the last colour, the least of
+/// the last colour that covers at least `k` games, if any does. fails
+/// before trying any if there'd be more than [`LIMIT`] combinations
+fn candidates(needs: &[Vec<u32>], width: usize, k: usize) -> Result<Vec<Vec<u32>>, TooBig> {
+    let Some(last) = width.checked_sub(1) else {
+        return Ok(Vec::new());
+    };
+
+    // the limits worth trying for each colour but the last
+    let values = (0..last)
+        .map(|colour| {
+            let mut values = needs.iter().map(|need| need[colour]).collect::<Vec<u32>>();
+            values.push(0);
+            values.sort();
+            values.dedup();
+            values
+        })
+        .collect::<Vec<Vec<u32>>>();
+
+    let combinations = values
+        .iter()
+        .try_fold(1usize, |product, values| product.checked_mul(values.len()));
+    if combinations.is_none_or(|combinations| combinations > LIMIT) {
+        return Err(TooBig { colours: width });
+    }
+
+    let mut found = Vec::new();
+    let mut pick = vec![0; last];
+    loop {
+        let prefix = pick
+            .iter()
+            .enumerate()
+            .map(|(colour, &idx)| values[colour][idx])
+            .collect::<Vec<u32>>();
+
+        let mut rest = needs
+            .iter()
+            .filter(|need| need.iter().zip(&prefix).all(|(need, limit)| need <= limit))
+            .map(|need| need[last])
+            .collect::<Vec<u32>>();
+        if rest.len() >= k {
+            rest.sort();
+            let mut bag = prefix;
+            bag.push(match k {
+                0 => 0,
+                _ => rest[k - 1],
+            });
+            found.push(bag);
+        }
+
+        // on to the next combination, like an odometer
+        let Some(colour) = (0..last).find(|&colour| pick[colour] + 1 < values[colour].len()) else {
+            break;
+        };
+        pick[colour] += 1;
+        pick[..colour].fill(0);
+    }
+
+    Ok(found)
+}
+
+fn total(bag: &[u32]) -> u64 {
+    bag.iter().map(|&count| count as u64).sum()
+}
+
+fn to_bag(colours: &[&str], counts: &[u32]) -> Bag {
+    Bag::new(colours.iter().copied().zip(counts.iter().copied())).unwrap()
+}
+
+/// every minimal bag that makes at least `k` games possible: no other bag
+/// that does has at most as many of every colour. fewest cubes first. fails
+/// if the games have too many colours and counts to search, see [`LIMIT`]
+pub fn frontier(games: &[Game], k: usize) -> Result<Vec<Bag>, TooBig> {
+    let colours = palette(games);
+    let bags = candidates(&needs(games, &colours), colours.len(), k)?;
+
+    let dominated = |bag: &Vec<u32>| {
+        bags.iter()
+            .any(|other| other != bag && other.iter().zip(bag).all(|(other, bag)| other <= bag))
+    };
+    let mut bags = bags
+        .iter()
+        .filter(|bag| !dominated(bag))
+        .cloned()
+        .collect::<Vec<Vec<u32>>>();
+
+    bags.sort_by_key(|bag| (total(bag), bag.clone()));
+    bags.dedup();
+    Ok(bags.iter().map(|bag| to_bag(&colours, bag)).collect())
+}
+
+/// the bag with the fewest cubes in total that makes at least `k` games
+/// possible, or `None` if there aren't `k` games. ties go to the one that
+/// comes first on the frontier. fails the same way [`frontier`] does
+pub fn smallest(games: &[Game], k: usize) -> Result<Option<Bag>, TooBig> {
+    Ok(frontier(games, k)?.into_iter().next())
+}
+
+/// the games that aren't possible with `bag`
+pub fn ruled_out<'g, 'a>(games: &'g [Game<'a>], bag: &Bag) -> Vec<&'g Game<'a>> {
+    games.iter().filter(|game| !game.possible(bag)).collect()
+}
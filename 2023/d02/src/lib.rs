@@ -0,0 +1,147 @@
+use std::io::BufRead;
+
+use scan::{LineReader, ParseError, StreamError};
+
+pub mod bag;
+pub mod constraint;
+pub mod game;
+pub mod infer;
+pub mod rows;
+
+pub use bag::Bag;
+pub use constraint::Constraints;
+pub use game::{Draw, Game, Round};
+
+use game::parse_game;
+
+/// one line of input, which in strict mode (when there's a bag to check
+/// against) can only draw colours the bag knows about
+fn read<'a>(idx: usize, line: &'a str, strict: Option<&Bag>) -> Result<Game<'a>, ParseError> {
+    let game = parse_game(idx, line)?;
+    if let Some(bag) = strict {
+        bag.check(idx, &game)?;
+    }
+
+    Ok(game)
+}
+
+fn games<'a>(binding: &'a str, strict: Option<&Bag>) -> Result<Vec<Game<'a>>, ParseError> {
+    let input = binding.split('\n').collect::<Vec<&str>>();
+
+    let mut games = Vec::new();
+
+    for (idx, game) in input.iter().enumerate() {
+        if game.is_empty() {
+            break;
+        }
+
+        games.push(read(idx, game, strict)?);
+    }
+
+    Ok(games)
+}
+
+/// reads every game, up to the first blank line
+pub fn parse(binding: &str) -> Result<Vec<Game<'_>>, ParseError> {
+    games(binding, None)
+}
+
+/// `parse`, but a game that draws a colour the bag doesn't have is an error
+pub fn parse_strict<'a>(binding: &'a str, bag: &Bag) -> Result<Vec<Game<'a>>, ParseError> {
+    games(binding, Some(bag))
+}
+
+/// sums `score` over every game from `reader`, up to the first blank line,
+/// without holding more than a line in memory
+fn stream(
+    reader: impl BufRead,
+    strict: Option<&Bag>,
+    mut score: impl FnMut(Game) -> u64,
+) -> Result<u64, StreamError> {
+    let mut lines = LineReader::new(reader);
+    let mut sum = 0;
+
+    while let Some((idx, game)) = lines.next_line()? {
+        if game.is_empty() {
+            break;
+        }
+
+        sum += score(read(idx, game, strict)?);
+    }
+
+    Ok(sum)
+}
+
+/// how many cubes of each colour are in the bag, according to the puzzle
+pub const BAG: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+pub fn part1(binding: &str) -> u64 {
+    part1_with(binding, &Bag::default(), false).unwrap()
+}
+
+/// sums the ids of every game that's possible with the given bag. colours
+/// the bag doesn't mention aren't limited, unless `strict` makes them errors
+pub fn part1_with(binding: &str, bag: &Bag, strict: bool) -> Result<u64, ParseError> {
+    part1_constrained(binding, &Constraints::from_bag(bag), strict.then_some(bag))
+}
+
+/// sums the ids of every game that breaks none of the constraints. with a
+/// bag for `strict`, games can only draw the colours it has
+pub fn part1_constrained(
+    binding: &str,
+    constraints: &Constraints,
+    strict: Option<&Bag>,
+) -> Result<u64, ParseError> {
+    let games = games(binding, strict)?;
+
+    Ok(games
+        .iter()
+        .filter(|game| constraints.allows(game))
+        .map(|game| game.id as u64)
+        .sum())
+}
+
+/// which games break which constraints, as `Constraints::report` lays it out
+pub fn report(
+    binding: &str,
+    constraints: &Constraints,
+    strict: Option<&Bag>,
+) -> Result<String, ParseError> {
+    let games = games(binding, strict)?;
+
+    Ok(constraints.report(&games))
+}
+
+pub fn part2(binding: &str) -> u64 {
+    part2_with(binding, &Bag::default(), false).unwrap()
+}
+
+/// sums every game's power over the bag's colours, and any others the game
+/// draws when that isn't an error
+pub fn part2_with(binding: &str, bag: &Bag, strict: bool) -> Result<u64, ParseError> {
+    let games = games(binding, strict.then_some(bag))?;
+
+    Ok(games.iter().map(|game| game.power(bag)).sum())
+}
+
+/// `part1_with` a line at a time, for inputs too big to read in one go
+pub fn part1_stream(reader: impl BufRead, bag: &Bag, strict: bool) -> Result<u64, StreamError> {
+    part1_stream_constrained(reader, &Constraints::from_bag(bag), strict.then_some(bag))
+}
+
+/// `part1_constrained` a line at a time
+pub fn part1_stream_constrained(
+    reader: impl BufRead,
+    constraints: &Constraints,
+    strict: Option<&Bag>,
+) -> Result<u64, StreamError> {
+    stream(reader, strict, |game| match constraints.allows(&game) {
+        true => game.id as u64,
+        false => 0,
+    })
+}
+
+/// `part2_with` a line at a time
+pub fn part2_stream(reader: impl BufRead, bag: &Bag, strict: bool) -> Result<u64, StreamError> {
+    stream(reader, strict.then_some(bag), |game| game.power(bag))
+}
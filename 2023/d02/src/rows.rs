@@ -0,0 +1,123 @@
+//! games as a table, one row for each colour drawn in each round:
+//!
+//! ```text
+//! game  round  colour  count
+//! 1     1      blue    3
+//! 1     1      red     4
+//! 1     2      red     1
+//! ```
+//!
+//! which is the shape spreadsheets and dataframes want. `games` puts the rows
+//! back together, so a table can be turned back into puzzle input.
+
+use std::collections::HashMap;
+
+use scan::ParseError;
+
+use crate::bag::is_colour;
+use crate::game::{Draw, Game, Round};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Row<'a> {
+    pub game: u32,
+    /// counting from 1
+    pub round: usize,
+    pub colour: &'a str,
+    pub count: u32,
+}
+
+/// every draw in every game, in the order the input has them
+pub fn rows<'a>(games: &[Game<'a>]) -> Vec<Row<'a>> {
+    let mut rows = Vec::new();
+
+    for game in games {
+        for (idx, round) in game.rounds.iter().enumerate() {
+            rows.extend(round.draws.iter().map(|draw| Row {
+                game: game.id,
+                round: idx + 1,
+                colour: draw.colour,
+                count: draw.count,
+            }));
+        }
+    }
+
+    rows
+}
+
+/// the games the rows describe, in the order each game first shows up. rows
+/// can come in any order, but a game can't skip a round, and a round can't
+/// draw the same colour twice. errors give the index of the row at fault,
+/// or of the game's first row for a missing round
+pub fn games<'a>(rows: &[Row<'a>]) -> Result<Vec<Game<'a>>, ParseError> {
+    // every round needs a row of its own, so a game can't have more rounds
+    // than rows. checking that first keeps a huge round number from asking
+    // for a huge list of rounds
+    let mut counts: HashMap<u32, usize> = HashMap::new();
+    for row in rows {
+        *counts.entry(row.game).or_default() += 1;
+    }
+
+    let mut games: Vec<Game> = Vec::new();
+    // where each game is in `games`, and the row it first showed up on
+    let mut seen: HashMap<u32, (usize, usize)> = HashMap::new();
+
+    for (idx, row) in rows.iter().enumerate() {
+        if row.round == 0 {
+            return Err(ParseError::new(idx, "rounds count from 1"));
+        }
+        let count = counts[&row.game];
+        if row.round > count {
+            return Err(ParseError::new(
+                idx,
+                format!(
+                    "game {} only has {} rows, so it can't have a round {}",
+                    row.game, count, row.round
+                ),
+            ));
+        }
+        if !is_colour(row.colour) {
+            return Err(ParseError::new(
+                idx,
+                format!("`{}` isn't a colour name", row.colour),
+            ));
+        }
+
+        let (at, _) = *seen.entry(row.game).or_insert_with(|| {
+            games.push(Game {
+                id: row.game,
+                rounds: Vec::new(),
+            });
+            (games.len() - 1, idx)
+        });
+        let rounds = &mut games[at].rounds;
+        if rounds.len() < row.round {
+            rounds.resize_with(row.round, Round::default);
+        }
+
+        let round = &mut rounds[row.round - 1];
+        if round.draws.iter().any(|draw| draw.colour == row.colour) {
+            return Err(ParseError::new(
+                idx,
+                format!(
+                    "game {} draws {} twice in round {}",
+                    row.game, row.colour, row.round
+                ),
+            ));
+        }
+        round.draws.push(Draw {
+            count: row.count,
+            colour: row.colour,
+        });
+    }
+
+    for game in &games {
+        if let Some(missing) = game.rounds.iter().position(|round| round.draws.is_empty()) {
+            return Err(ParseError::new(
+                seen[&game.id].1,
+                format!("game {} has no rows for round {}", game.id, missing + 1),
+            ));
+        }
+    }
+
+    Ok(games)
+}
@@ -0,0 +1,112 @@
+//! constraints beyond the bag's limits, and how they're reported
+
+use aoc2023_d02::constraint::{Constraint, Scope};
+use aoc2023_d02::{parse, Bag, Constraints};
+
+fn constraints(spec: &str) -> Constraints {
+    Constraints::parse(spec, &Bag::default()).unwrap()
+}
+
+/// the error for a spec, as `ParseError` prints it
+fn error(spec: &str) -> String {
+    Constraints::parse(spec, &Bag::default())
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn bag_limits() {
+    // the puzzle's bag holds 12 red, 13 green and 14 blue
+    assert_eq!(
+        constraints("red <= bag, round <= bag").rules,
+        [
+            Constraint {
+                scope: Scope::Colour("red".to_string()),
+                max: 12
+            },
+            Constraint {
+                scope: Scope::Round,
+                max: 39
+            },
+        ]
+    );
+
+    assert_eq!(
+        error("yellow <= bag"),
+        "line 1: the bag has no limit for yellow"
+    );
+    assert_eq!(
+        error("game <= bag"),
+        "line 1: `bag` is a limit for a colour or a round, not a whole game"
+    );
+}
+
+#[test]
+fn bad_rules() {
+    assert_eq!(
+        error("red < 12"),
+        "line 1: `red < 12` should look like `<what> <= <limit>`"
+    );
+    assert_eq!(
+        error("dark blue <= 3"),
+        "line 1: can't constrain `dark blue`: expected a colour, `round` or `game`"
+    );
+    assert_eq!(
+        error("red <= lots"),
+        "line 1: expected a number or `bag`, found `lots`"
+    );
+
+    // errors are on the line of the spec they're found on
+    assert_eq!(
+        error("red <= 12\ngreen <= 13, blue = 14"),
+        "line 2: `blue = 14` should look like `<what> <= <limit>`"
+    );
+}
+
+#[test]
+fn first_violating_round() {
+    let games = parse("Game 5: 1 red; 13 red, 2 blue; 14 red\n").unwrap();
+    let rules = constraints("red <= 12, round <= 14, game <= 20");
+
+    let violations = rules
+        .violations(&games[0])
+        .iter()
+        .map(|violation| (violation.round, violation.found))
+        .collect::<Vec<_>>();
+    // rounds count from 1, and only the first one to break a rule is named
+    assert_eq!(violations, [(Some(2), 13), (Some(2), 15), (None, 30)]);
+
+    let described = rules
+        .violations(&games[0])
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>();
+    assert_eq!(
+        described,
+        [
+            "red <= 12 (13 red in round 2)",
+            "round <= 14 (15 cubes in round 2)",
+            "game <= 20 (30 cubes in all)",
+        ]
+    );
+}
+
+#[test]
+fn report() {
+    let games = parse("Game 1: 3 red\nGame 2: 20 red\nGame 3: 1 blue, 30 green\n").unwrap();
+
+    assert_eq!(
+        constraints("red <= 12").report(&games),
+        "game 2: red <= 12 (20 red in round 1)\n1 of 3 games is impossible\n"
+    );
+    assert_eq!(
+        constraints("round <= 10").report(&games),
+        "game 2: round <= 10 (20 cubes in round 1)\n\
+         game 3: round <= 10 (31 cubes in round 1)\n\
+         2 of 3 games are impossible\n"
+    );
+    assert_eq!(
+        constraints("game <= 100").report(&games),
+        "0 of 3 games are impossible\n"
+    );
+}
@@ -0,0 +1,115 @@
+//! the game parser, and what it says about lines it won't read
+
+use aoc2023_d02::{parse, Draw, Game, Round};
+
+/// the error for a single line, as `ParseError` prints it
+fn error(line: &str) -> String {
+    parse(line).unwrap_err().to_string()
+}
+
+#[test]
+fn games() {
+    let games = parse("Game 1: 3 blue, 4 red; 1 red\nGame 12: 2 mauve\n").unwrap();
+    assert_eq!(
+        games[0],
+        Game {
+            id: 1,
+            rounds: vec![
+                Round {
+                    draws: vec![
+                        Draw {
+                            count: 3,
+                            colour: "blue"
+                        },
+                        Draw {
+                            count: 4,
+                            colour: "red"
+                        },
+                    ]
+                },
+                Round {
+                    draws: vec![Draw {
+                        count: 1,
+                        colour: "red"
+                    }]
+                },
+            ],
+        }
+    );
+
+    // any single word is a colour
+    assert_eq!(games[1].id, 12);
+    assert_eq!(games[1].to_string(), "Game 12: 2 mauve");
+}
+
+#[test]
+fn malformed_ids() {
+    assert_eq!(
+        error("Game x: 1 red"),
+        "line 1: expected a game id, found `x`"
+    );
+    assert_eq!(
+        error("Game -1: 1 red"),
+        "line 1: expected a game id, found `-1`"
+    );
+    assert_eq!(
+        error("Game : 1 red"),
+        "line 1: expected a game id, found ``"
+    );
+    assert_eq!(
+        error("Game 99999999999: 1 red"),
+        "line 1: game id `99999999999` is too big"
+    );
+    assert_eq!(
+        error("Gme 1: 1 red"),
+        "line 1: expected `Game <id>`, found `Gme 1`"
+    );
+    assert_eq!(
+        error("Game 1 1 red"),
+        "line 1: missing `:` after the game id"
+    );
+}
+
+#[test]
+fn empty_rounds() {
+    assert_eq!(error("Game 1:"), "line 1: empty draw in round 1");
+    assert_eq!(
+        error("Game 1: 3 blue;; 1 red"),
+        "line 1: empty draw in round 2"
+    );
+    assert_eq!(error("Game 1: 3 blue;"), "line 1: empty draw in round 2");
+    assert_eq!(
+        error("Game 1: 3 blue, , 1 red"),
+        "line 1: empty draw in round 1"
+    );
+}
+
+#[test]
+fn bad_draws() {
+    assert_eq!(
+        error("Game 1: 3 dark blue"),
+        "line 1: `dark blue` isn't a colour name"
+    );
+    assert_eq!(
+        error("Game 1: 3"),
+        "line 1: `3` should be a count and a colour"
+    );
+    assert_eq!(
+        error("Game 1: blue 3"),
+        "line 1: expected a count, found `blue`"
+    );
+    assert_eq!(
+        error("Game 1: 1 red; 3 blue, 1 blue"),
+        "line 1: blue is drawn twice in round 2"
+    );
+}
+
+#[test]
+fn line_numbers() {
+    let err = parse("Game 1: 1 red\nGame 2: 2 green\nGame 3: 3 blue,\n").unwrap_err();
+    assert_eq!(err.line, 3);
+    assert_eq!(err.reason, "empty draw in round 1");
+
+    // everything after the first blank line is ignored, however it looks
+    assert_eq!(parse("Game 1: 1 red\n\nnot a game\n").unwrap().len(), 1);
+}
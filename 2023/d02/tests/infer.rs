@@ -0,0 +1,134 @@
+//! `infer`'s questions, asked of the puzzle's example
+
+use aoc2023_d02::infer::{self, palette, ruled_out, TooBig, LIMIT};
+use aoc2023_d02::{parse, Bag, Game};
+
+const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+fn games() -> Vec<Game<'static>> {
+    parse(EXAMPLE).unwrap()
+}
+
+fn smallest(games: &[Game], k: usize) -> Option<Bag> {
+    infer::smallest(games, k).unwrap()
+}
+
+fn frontier(games: &[Game], k: usize) -> Vec<Bag> {
+    infer::frontier(games, k).unwrap()
+}
+
+fn described(bags: &[Bag]) -> Vec<String> {
+    bags.iter().map(Bag::to_string).collect()
+}
+
+#[test]
+fn smallest_bags() {
+    let games = games();
+    assert_eq!(palette(&games), ["blue", "red", "green"]);
+
+    let smallest = |k| smallest(&games, k).map(|bag| bag.to_string());
+    // game 2 needs the fewest cubes on its own
+    assert_eq!(smallest(1).as_deref(), Some("4 blue, 1 red, 3 green"));
+    // games 2 and 5, and games 1 and 2, both need 13. ties go to fewer of
+    // the palette's first colour
+    assert_eq!(smallest(2).as_deref(), Some("4 blue, 6 red, 3 green"));
+    assert_eq!(smallest(3).as_deref(), Some("6 blue, 6 red, 3 green"));
+    // every game at once needs part 2's minimum of each colour
+    assert_eq!(smallest(5).as_deref(), Some("15 blue, 20 red, 13 green"));
+
+    assert_eq!(smallest(0).as_deref(), Some("0 blue, 0 red, 0 green"));
+    assert_eq!(smallest(6), None);
+}
+
+#[test]
+fn frontiers() {
+    let games = games();
+
+    assert_eq!(
+        described(&frontier(&games, 2)),
+        ["4 blue, 6 red, 3 green", "6 blue, 4 red, 3 green"]
+    );
+    // the smallest bag is always the first on the frontier
+    for k in 0..=5 {
+        assert_eq!(
+            frontier(&games, k).first(),
+            smallest(&games, k).as_ref(),
+            "{}",
+            k
+        );
+    }
+    assert!(frontier(&games, 6).is_empty());
+
+    // no bag on the frontier holds at most as many of every colour as
+    // another one does
+    let within = |a: &Bag, b: &Bag| a.limits().all(|(colour, n)| b.limit(colour) >= Some(n));
+    for k in 0..=5 {
+        let bags = frontier(&games, k);
+        for (i, bag) in bags.iter().enumerate() {
+            for other in &bags[i + 1..] {
+                assert!(
+                    !within(bag, other) && !within(other, bag),
+                    "{} and {}",
+                    bag,
+                    other
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn ruled_out_games() {
+    let games = games();
+    let ids = |bag: &Bag| {
+        ruled_out(&games, bag)
+            .iter()
+            .map(|game| game.id)
+            .collect::<Vec<u32>>()
+    };
+
+    assert_eq!(ids(&Bag::default()), [3, 4]);
+    assert_eq!(ids(&smallest(&games, 5).unwrap()), []);
+    assert_eq!(
+        ids(&Bag::parse("4 blue, 1 red, 3 green").unwrap()),
+        [1, 3, 4, 5]
+    );
+}
+
+#[test]
+fn too_many_counts() {
+    // every game needs a different count of each colour, so there are 31
+    // limits worth trying for each colour but the last
+    let binding = |colours: &[&str]| {
+        (1..=30)
+            .map(|id| {
+                let draw = colours
+                    .iter()
+                    .map(|colour| format!("{} {}", id, colour))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!("Game {}: {}\n", id, draw)
+            })
+            .collect::<String>()
+    };
+
+    // 31 * 31 is few enough to search
+    let three = binding(&["red", "green", "blue"]);
+    assert_eq!(
+        smallest(&parse(&three).unwrap(), 3).unwrap().to_string(),
+        "3 red, 3 green, 3 blue"
+    );
+
+    // but 31 to the fourth isn't
+    assert!(31usize.pow(4) > LIMIT);
+    let five = binding(&["red", "green", "blue", "pink", "grey"]);
+    let games = parse(&five).unwrap();
+    assert_eq!(infer::frontier(&games, 3), Err(TooBig { colours: 5 }));
+    assert_eq!(infer::smallest(&games, 3), Err(TooBig { colours: 5 }));
+}
@@ -0,0 +1,52 @@
+//! strict mode turns colours the bag doesn't have from unlimited into errors
+
+use std::io::Cursor;
+
+use aoc2023_d02::{parse, parse_strict, part1_stream, part1_with, part2_stream, part2_with, Bag};
+
+const GAMES: &str = "Game 1: 2 red, 1 green, 5 blue\nGame 2: 3 blue, 2 yellow; 1 red, 4 green\n";
+
+const UNKNOWN: &str = "line 2: unknown colour `yellow`; the bag only has red, green, blue";
+
+#[test]
+fn lenient_accepts_other_colours() {
+    assert_eq!(parse(GAMES).unwrap().len(), 2);
+
+    // the bag doesn't limit yellow, so both games are possible...
+    assert_eq!(part1_with(GAMES, &Bag::default(), false), Ok(3));
+    // ...and yellow counts towards game 2's power: 1 * 4 * 3 * 2
+    assert_eq!(part2_with(GAMES, &Bag::default(), false), Ok(10 + 24));
+}
+
+#[test]
+fn strict_rejects_them() {
+    let err = parse_strict(GAMES, &Bag::default()).unwrap_err();
+    assert_eq!(err.line, 2);
+    assert_eq!(err.to_string(), UNKNOWN);
+
+    let bag = Bag::default();
+    let err = part1_stream(Cursor::new(GAMES), &bag, true).unwrap_err();
+    assert_eq!(err.to_string(), UNKNOWN);
+    let err = part2_stream(Cursor::new(GAMES), &bag, true).unwrap_err();
+    assert_eq!(err.to_string(), UNKNOWN);
+
+    // and the whole input fails the same way
+    let err = part1_with(GAMES, &bag, true).unwrap_err();
+    assert_eq!(err.to_string(), UNKNOWN);
+    let err = part2_with(GAMES, &bag, true).unwrap_err();
+    assert_eq!(err.to_string(), UNKNOWN);
+
+    // the same games are fine streamed leniently
+    assert_eq!(part1_stream(Cursor::new(GAMES), &bag, false).unwrap(), 3);
+}
+
+#[test]
+fn strict_with_a_bag_that_has_them() {
+    let bag = Bag::new([("red", 12), ("green", 13), ("blue", 14), ("yellow", 1)]).unwrap();
+    assert!(parse_strict(GAMES, &bag).is_ok());
+
+    // now yellow is limited, and game 2 draws too many
+    assert_eq!(part1_with(GAMES, &bag, true), Ok(1));
+    // and game 1, which never draws yellow, has a power of 0
+    assert_eq!(part2_with(GAMES, &bag, true), Ok(24));
+}
@@ -0,0 +1,66 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use rng::Rng;
+use std::hint::black_box;
+
+/// a few very long rows crowded with numbers and symbols, which is where
+/// comparing against everything in the neighbouring rows hurts most
+fn schematic(rows: usize, cols: usize) -> String {
+    const SYMBOLS: &[u8] = b"*#+$@%&=-/";
+
+    let mut input = String::new();
+    let mut rng = Rng::new(0);
+
+    for _ in 0..rows {
+        let mut col = 0;
+        while col < cols {
+            match rng.range(0, 9) {
+                0..=3 => {
+                    let len = rng.range(1, 3) as usize;
+                    for _ in 0..len {
+                        input.push((b'0' + rng.range(0, 9) as u8) as char);
+                    }
+                    input.push('.');
+                    col += len + 1;
+                }
+                4..=6 => {
+                    input.push(*rng.pick(SYMBOLS) as char);
+                    col += 1;
+                }
+                _ => {
+                    input.push('.');
+                    col += 1;
+                }
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn bench(c: &mut Criterion) {
+    let input = schematic(20, 50_000);
+
+    let mut group = c.benchmark_group("schematic");
+    group.sample_size(10);
+
+    group.bench_function("index part 1", |b| {
+        b.iter(|| aoc2023_d03::part1(black_box(&input)))
+    });
+
+    group.bench_function("rows part 1", |b| {
+        b.iter(|| aoc2023_d03::rows::part1(black_box(&input)))
+    });
+
+    group.bench_function("index part 2", |b| {
+        b.iter(|| aoc2023_d03::part2(black_box(&input)))
+    });
+
+    group.bench_function("rows part 2", |b| {
+        b.iter(|| aoc2023_d03::rows::part2(black_box(&input)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,244 @@
+//! what makes a gear, and what it's worth. the puzzle's gear is a `*`
+//! touching exactly two numbers, worth their product, but each of those three
+//! can be swapped out:
+//!
+//! ```text
+//! symbols   a character class, like [*] or [*#]
+//! count     2 for exactly two numbers, 2+ for at least two, 2-4 for two to four
+//! reduce    product, sum or max of the numbers
+//! ```
+//!
+//! every symbol in the class is a candidate, and `candidates` says which ones
+//! counted and why the rest didn't. a ratio, or the total of them, that
+//! doesn't fit in a u64 is an `Overflow` rather than a wrapped number.
+
+use std::fmt;
+
+use crate::rules::{Class, Error};
+use crate::token::{Number, Symbol};
+
+/// how many numbers a gear has to touch
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Count {
+    Exactly(usize),
+    AtLeast(usize),
+    /// inclusive at both ends
+    Between(usize, usize),
+}
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Count::Exactly(k) => write!(f, "{}", k),
+            Count::AtLeast(k) => write!(f, "{}+", k),
+            Count::Between(lo, hi) => write!(f, "{}-{}", lo, hi),
+        }
+    }
+}
+
+impl Count {
+    /// reads `2`, `2+` or `2-4`
+    pub fn parse(spec: &str) -> Result<Count, Error> {
+        let spec = spec.trim();
+        let number = |text: &str| text.trim().parse::<usize>().ok();
+
+        let count = if let Some(k) = spec.strip_suffix('+') {
+            number(k).map(Count::AtLeast)
+        } else if let Some((lo, hi)) = spec.split_once('-') {
+            match (number(lo), number(hi)) {
+                (Some(lo), Some(hi)) if lo <= hi => Some(Count::Between(lo, hi)),
+                _ => None,
+            }
+        } else {
+            number(spec).map(Count::Exactly)
+        };
+
+        count.ok_or_else(|| Error::Count(spec.to_string()))
+    }
+
+    pub fn allows(&self, n: usize) -> bool {
+        match *self {
+            Count::Exactly(k) => n == k,
+            Count::AtLeast(k) => n >= k,
+            Count::Between(lo, hi) => lo <= n && n <= hi,
+        }
+    }
+
+    /// why `n` numbers aren't enough, or are too many
+    fn describe(&self) -> String {
+        match *self {
+            Count::Exactly(k) => format!("exactly {}", k),
+            Count::AtLeast(k) => format!("at least {}", k),
+            Count::Between(lo, hi) => format!("between {} and {}", lo, hi),
+        }
+    }
+}
+
+/// a ratio, or the sum of them, got too big for a u64
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the answer is too big for a u64")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// how a gear's numbers make its ratio
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Reduce {
+    /// the puzzle's. with no numbers at all, that's 1
+    #[default]
+    Product,
+    Sum,
+    /// the biggest number, or 0 with none
+    Max,
+}
+
+impl fmt::Display for Reduce {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reduce::Product => write!(f, "product"),
+            Reduce::Sum => write!(f, "sum"),
+            Reduce::Max => write!(f, "max"),
+        }
+    }
+}
+
+impl Reduce {
+    pub fn parse(spec: &str) -> Result<Reduce, Error> {
+        match spec.trim() {
+            "product" => Ok(Reduce::Product),
+            "sum" => Ok(Reduce::Sum),
+            "max" => Ok(Reduce::Max),
+            spec => Err(Error::Reduce(spec.to_string())),
+        }
+    }
+
+    pub fn apply(&self, numbers: &[Number]) -> Result<u64, Overflow> {
+        let mut values = numbers.iter().map(|number| number.value as u64);
+        match self {
+            Reduce::Product => values.try_fold(1, u64::checked_mul).ok_or(Overflow),
+            Reduce::Sum => values.try_fold(0, u64::checked_add).ok_or(Overflow),
+            Reduce::Max => Ok(values.max().unwrap_or(0)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GearRule {
+    pub symbols: Class,
+    pub count: Count,
+    pub reduce: Reduce,
+}
+
+impl Default for GearRule {
+    /// a `*` touching exactly two numbers, worth their product
+    fn default() -> GearRule {
+        GearRule {
+            symbols: Class::parse("[*]").unwrap(),
+            count: Count::Exactly(2),
+            reduce: Reduce::Product,
+        }
+    }
+}
+
+/// a symbol that might be a gear, and whether it is
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Candidate {
+    pub symbol: Symbol,
+    /// every number touching it, in reading order
+    pub numbers: Vec<Number>,
+    /// what it adds to the answer, if the rule counted it
+    pub ratio: Option<Result<u64, Overflow>>,
+}
+
+impl Candidate {
+    pub fn new(symbol: Symbol, numbers: Vec<Number>, rule: &GearRule) -> Candidate {
+        let ratio = rule
+            .count
+            .allows(numbers.len())
+            .then(|| rule.reduce.apply(&numbers));
+        Candidate {
+            symbol,
+            numbers,
+            ratio,
+        }
+    }
+
+    /// e.g. `* at (1, 3) touches 467, 35: product 16345`, or with `rule`'s
+    /// count, why it wasn't counted
+    pub fn explain(&self, rule: &GearRule) -> String {
+        let Symbol { c, row, col } = self.symbol;
+        let numbers = match self.numbers.len() {
+            0 => "nothing".to_string(),
+            _ => self
+                .numbers
+                .iter()
+                .map(|number| number.value.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        let verdict = match self.ratio {
+            Some(Ok(ratio)) => format!("{} {}", rule.reduce, ratio),
+            Some(Err(Overflow)) => format!("{} too big for a u64", rule.reduce),
+            None => format!(
+                "not a gear, it needs {} number{}",
+                rule.count.describe(),
+                if rule.count == Count::Exactly(1) {
+                    ""
+                } else {
+                    "s"
+                }
+            ),
+        };
+        format!(
+            "{} at ({}, {}) touches {}: {}",
+            c, row, col, numbers, verdict
+        )
+    }
+}
+
+/// what the counted candidates add up to
+pub fn total(candidates: &[Candidate]) -> Result<u64, Overflow> {
+    candidates
+        .iter()
+        .filter_map(|candidate| candidate.ratio)
+        .try_fold(0, |total: u64, ratio| {
+            total.checked_add(ratio?).ok_or(Overflow)
+        })
+}
+
+/// every candidate explained a line at a time, then a total, e.g.
+///
+/// ```text
+/// * at (1, 3) touches 467, 35: product 16345
+/// * at (4, 3) touches 617: not a gear, it needs exactly 2 numbers
+/// 1 of 2 candidates counted, 16345 in all
+/// ```
+pub fn report(candidates: &[Candidate], rule: &GearRule) -> String {
+    let mut out = String::new();
+    for candidate in candidates {
+        out += &candidate.explain(rule);
+        out += "\n";
+    }
+
+    let counted = candidates
+        .iter()
+        .filter(|candidate| candidate.ratio.is_some())
+        .count();
+    let total = match total(candidates) {
+        Ok(total) => format!("{} in all", total),
+        Err(Overflow) => "too big for a u64 in all".to_string(),
+    };
+    out += &format!(
+        "{} of {} candidates counted, {}\n",
+        counted,
+        candidates.len(),
+        total
+    );
+    out
+}
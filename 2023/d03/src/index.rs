@@ -0,0 +1,59 @@
+//! which number, if any, covers each cell of the schematic. with that, the
+//! numbers touching a symbol are whatever's in the handful of cells around
+//! it, so nothing has to be compared against everything else in its rows,
+//! and the whole schematic takes time in proportion to its size.
+
+use crate::rules::Adjacency;
+use crate::token::{Number, Tokens};
+
+pub struct Index<'t> {
+    numbers: &'t [Number],
+    /// per row, the index into `numbers` of the number covering each column,
+    /// up to the end of the row's last number
+    cells: Vec<Vec<Option<u32>>>,
+}
+
+impl<'t> Index<'t> {
+    pub fn new(tokens: &'t Tokens) -> Index<'t> {
+        let mut cells = vec![Vec::new(); tokens.rows];
+
+        for (id, number) in tokens.numbers.iter().enumerate() {
+            let row: &mut Vec<Option<u32>> = &mut cells[number.row];
+            if row.len() < number.end {
+                row.resize(number.end, None);
+            }
+            row[number.start..number.end].fill(Some(id as u32));
+        }
+
+        Index {
+            numbers: &tokens.numbers,
+            cells,
+        }
+    }
+
+    /// the number covering `(row, col)`, as its index in the tokens
+    pub fn at(&self, row: usize, col: usize) -> Option<usize> {
+        let id = self.cells.get(row)?.get(col).copied()??;
+        Some(id as usize)
+    }
+
+    /// the numbers touching `(row, col)` by `adjacency`, as indices into the
+    /// tokens' numbers, in reading order and once each
+    pub fn around(&self, row: usize, col: usize, adjacency: &Adjacency) -> Vec<usize> {
+        let mut found = adjacency
+            .cells(row, col)
+            .filter_map(|(row, col)| self.at(row, col))
+            .collect::<Vec<usize>>();
+        found.sort_unstable();
+        found.dedup();
+        found
+    }
+
+    /// `around`, as the numbers themselves
+    pub fn numbers_around(&self, row: usize, col: usize, adjacency: &Adjacency) -> Vec<Number> {
+        self.around(row, col, adjacency)
+            .into_iter()
+            .map(|id| self.numbers[id])
+            .collect()
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+
+use scan::ParseError;
+
+pub mod gear;
+pub mod index;
+pub mod rows;
+pub mod rules;
+pub mod token;
+
+pub use gear::{Candidate, Count, GearRule, Overflow, Reduce};
+pub use index::Index;
+pub use rules::{Adjacency, Class, Rules};
+pub use token::{tokenize, Number, Symbol, Tokens};
+
+/// why part 2 has no answer: a schematic it can't read, or ratios too big
+/// to add up
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Part2Error {
+    Parse(ParseError),
+    Overflow(Overflow),
+}
+
+impl fmt::Display for Part2Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part2Error::Parse(err) => write!(f, "{}", err),
+            Part2Error::Overflow(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Part2Error {}
+
+impl From<ParseError> for Part2Error {
+    fn from(err: ParseError) -> Part2Error {
+        Part2Error::Parse(err)
+    }
+}
+
+impl From<Overflow> for Part2Error {
+    fn from(err: Overflow) -> Part2Error {
+        Part2Error::Overflow(err)
+    }
+}
+
+/// every number in the schematic, alongside whether it touches a symbol
+pub fn part_numbers(binding: &str) -> Result<Vec<(Number, bool)>, ParseError> {
+    part_numbers_with(binding, &Rules::default())
+}
+
+/// `part_numbers`, with other ideas of what a symbol is and what touching is
+pub fn part_numbers_with(binding: &str, rules: &Rules) -> Result<Vec<(Number, bool)>, ParseError> {
+    let tokens = tokenize(binding)?;
+    let index = Index::new(&tokens);
+
+    let mut touched = vec![false; tokens.numbers.len()];
+    for symbol in &tokens.symbols {
+        if rules.symbols.matches(symbol.c) {
+            for id in index.around(symbol.row, symbol.col, &rules.adjacency) {
+                touched[id] = true;
+            }
+        }
+    }
+
+    Ok(tokens.numbers.iter().copied().zip(touched).collect())
+}
+
+/// every `*` in the schematic, alongside the numbers touching it and, for
+/// the ones touching exactly two, their product
+pub fn gears(binding: &str) -> Result<Vec<Candidate>, ParseError> {
+    gears_with(binding, &Rules::default())
+}
+
+/// every symbol the rules' gears can be made of, alongside the numbers it
+/// touches and what it's worth if the rules count it
+pub fn gears_with(binding: &str, rules: &Rules) -> Result<Vec<Candidate>, ParseError> {
+    let tokens = tokenize(binding)?;
+    let index = Index::new(&tokens);
+
+    let found = tokens
+        .symbols
+        .iter()
+        .filter(|symbol| rules.gears.symbols.matches(symbol.c))
+        .map(|gear| {
+            let adjacent = index.numbers_around(gear.row, gear.col, &rules.adjacency);
+            Candidate::new(*gear, adjacent, &rules.gears)
+        })
+        .collect();
+
+    Ok(found)
+}
+
+/// every gear candidate and why it did or didn't count, as `gear::report`
+/// lays it out
+pub fn report(binding: &str, rules: &Rules) -> Result<String, ParseError> {
+    Ok(gear::report(&gears_with(binding, rules)?, &rules.gears))
+}
+
+pub fn part1(binding: &str) -> u64 {
+    part1_with(binding, &Rules::default()).unwrap()
+}
+
+pub fn part1_with(binding: &str, rules: &Rules) -> Result<u64, ParseError> {
+    Ok(part_numbers_with(binding, rules)?
+        .iter()
+        .filter(|(_, ok)| *ok)
+        .map(|(number, _)| number.value as u64)
+        .sum())
+}
+
+pub fn part2(binding: &str) -> Result<u64, Part2Error> {
+    part2_with(binding, &Rules::default())
+}
+
+/// sums the ratio of every gear, by the rules' idea of a gear. part 1's
+/// symbol class doesn't come into it
+pub fn part2_with(binding: &str, rules: &Rules) -> Result<u64, Part2Error> {
+    Ok(gear::total(&gears_with(binding, rules)?)?)
+}
@@ -0,0 +1,5 @@
+fn main() {
+    let binding = std::fs::read_to_string("input.txt").unwrap();
+
+    print!("{}", aoc2023_d03::part2(&binding).unwrap());
+}
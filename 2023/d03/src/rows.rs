@@ -0,0 +1,86 @@
+//! the way both parts used to find what touches what: sort the numbers and
+//! symbols into rows, then compare each one against everything in the rows
+//! around it. it's quick enough for the puzzle's 140 columns, but a row with
+//! a lot in it gets compared against its neighbours over and over, so it
+//! slows down on long, busy rows. kept as the `rows` variant to race the
+//! index against.
+
+use scan::ParseError;
+
+use crate::gear;
+use crate::{tokenize, Candidate, Number, Part2Error, Rules, Symbol};
+
+/// sorts `items` into a list per row
+fn by_row<T: Copy>(items: &[T], rows: usize, row: fn(&T) -> usize) -> Vec<Vec<T>> {
+    let mut by_row = vec![Vec::new(); rows];
+    for item in items {
+        by_row[row(item)].push(*item);
+    }
+    by_row
+}
+
+/// pulls in the entries of `rows` from `radius` rows above `j` to `radius` rows below
+fn neighbourhood<T>(rows: &[Vec<T>], j: usize, radius: usize) -> impl Iterator<Item = &T> {
+    rows[j.saturating_sub(radius)..(j + radius + 1).min(rows.len())]
+        .iter()
+        .flatten()
+}
+
+/// `crate::part_numbers_with`, a row at a time
+pub fn part_numbers_with(binding: &str, rules: &Rules) -> Result<Vec<(Number, bool)>, ParseError> {
+    let tokens = tokenize(binding)?;
+    let radius = rules.adjacency.radius();
+    let symbols = tokens
+        .symbols
+        .iter()
+        .copied()
+        .filter(|symbol| rules.symbols.matches(symbol.c))
+        .collect::<Vec<Symbol>>();
+    let symbols = by_row(&symbols, tokens.rows, |symbol| symbol.row);
+
+    let parts = tokens
+        .numbers
+        .iter()
+        .map(|number| {
+            let ok = neighbourhood(&symbols, number.row, radius)
+                .any(|symbol| rules.adjacency.touches(number, symbol.row, symbol.col));
+            (*number, ok)
+        })
+        .collect();
+
+    Ok(parts)
+}
+
+/// `crate::gears_with`, a row at a time
+pub fn gears_with(binding: &str, rules: &Rules) -> Result<Vec<Candidate>, ParseError> {
+    let tokens = tokenize(binding)?;
+    let (adjacency, radius) = (&rules.adjacency, rules.adjacency.radius());
+    let numbers = by_row(&tokens.numbers, tokens.rows, |number| number.row);
+
+    let found = tokens
+        .symbols
+        .iter()
+        .filter(|symbol| rules.gears.symbols.matches(symbol.c))
+        .map(|gear| {
+            let adjacent = neighbourhood(&numbers, gear.row, radius)
+                .filter(|number| adjacency.touches(number, gear.row, gear.col))
+                .copied()
+                .collect::<Vec<Number>>();
+            Candidate::new(*gear, adjacent, &rules.gears)
+        })
+        .collect();
+
+    Ok(found)
+}
+
+pub fn part1(binding: &str) -> Result<u64, ParseError> {
+    Ok(part_numbers_with(binding, &Rules::default())?
+        .iter()
+        .filter(|(_, ok)| *ok)
+        .map(|(number, _)| number.value as u64)
+        .sum())
+}
+
+pub fn part2(binding: &str) -> Result<u64, Part2Error> {
+    Ok(gear::total(&gears_with(binding, &Rules::default())?)?)
+}
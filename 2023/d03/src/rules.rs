@@ -0,0 +1,223 @@
+//! what counts as a symbol, and what counts as touching one. by default a
+//! symbol is anything that isn't a digit or a `.`, and a number touches
+//! anything in the box one tile around it. either can be swapped out:
+//!
+//! ```text
+//! [*#+$]         only these characters are symbols
+//! [^a-z]         anything but a lowercase letter is
+//! 4              only the tiles directly beside, above or below a digit
+//! 8              the box one tile around the number (the default)
+//! chebyshev:2    the box two tiles around it
+//! ```
+//!
+//! a character class is written the way a regex writes one, with ranges like
+//! `a-z`, a leading `^` to match everything else, and `\` in front of a
+//! `]`, `\`, `-` or `^` that should be taken literally. digits and `.` are
+//! never symbols, whatever the class says.
+
+use std::fmt;
+
+use crate::gear::GearRule;
+use crate::token::Number;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// a character class that doesn't parse, and why
+    Class(String, String),
+    Adjacency(String),
+    /// a gear's count of numbers that doesn't parse
+    Count(String),
+    /// a gear reduction that doesn't exist
+    Reduce(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Class(spec, reason) => write!(f, "bad character class `{}`: {}", spec, reason),
+            Error::Adjacency(spec) => write!(
+                f,
+                "`{}` isn't an adjacency; expected `4`, `8` or `chebyshev:<radius>`",
+                spec
+            ),
+            Error::Count(spec) => write!(
+                f,
+                "`{}` isn't a count of numbers; expected `2`, `2+` or `2-4`",
+                spec
+            ),
+            Error::Reduce(spec) => write!(
+                f,
+                "`{}` isn't a reduction; expected `product`, `sum` or `max`",
+                spec
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// the characters that count as symbols
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Class {
+    /// inclusive ranges of characters
+    ranges: Vec<(char, char)>,
+    /// whether the class matches everything outside `ranges` instead
+    negated: bool,
+}
+
+impl Default for Class {
+    /// everything that isn't a digit or a `.`, as an empty negated class
+    fn default() -> Class {
+        Class {
+            ranges: Vec::new(),
+            negated: true,
+        }
+    }
+}
+
+impl Class {
+    pub fn parse(spec: &str) -> Result<Class, Error> {
+        let error = |reason: &str| Error::Class(spec.to_string(), reason.to_string());
+
+        let Some(inner) = spec
+            .strip_prefix('[')
+            .and_then(|spec| spec.strip_suffix(']'))
+        else {
+            return Err(error("it should be wrapped in `[` and `]`"));
+        };
+        let (negated, inner) = match inner.strip_prefix('^') {
+            Some(inner) => (true, inner),
+            None => (false, inner),
+        };
+
+        // each character, and whether it was escaped
+        let mut chars = Vec::new();
+        let mut iter = inner.chars();
+        while let Some(c) = iter.next() {
+            match c {
+                '\\' => match iter.next() {
+                    Some(c) => chars.push((c, true)),
+                    None => return Err(error("it ends in a `\\` with nothing to escape")),
+                },
+                ']' => return Err(error("a `]` inside it needs a `\\` in front")),
+                c => chars.push((c, false)),
+            }
+        }
+
+        let mut ranges = Vec::new();
+        let mut idx = 0;
+        while idx < chars.len() {
+            let (lo, _) = chars[idx];
+            match chars.get(idx + 1..idx + 3) {
+                Some(&[('-', false), (hi, _)]) => {
+                    if hi < lo {
+                        return Err(error(&format!("the range `{}-{}` is backwards", lo, hi)));
+                    }
+                    ranges.push((lo, hi));
+                    idx += 3;
+                }
+                _ => {
+                    ranges.push((lo, lo));
+                    idx += 1;
+                }
+            }
+        }
+
+        Ok(Class { ranges, negated })
+    }
+
+    pub fn matches(&self, c: char) -> bool {
+        if c.is_ascii_digit() || c == '.' {
+            return false;
+        }
+        let inside = self.ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi);
+        inside != self.negated
+    }
+}
+
+/// which tiles around a number it touches
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Adjacency {
+    /// the tiles sharing an edge with one of its digits
+    Four,
+    /// the tiles sharing an edge or a corner with one of its digits
+    #[default]
+    Eight,
+    /// the tiles within this many steps of a digit, counting diagonal steps
+    /// as one. a radius of 1 is the same as `Eight`
+    Chebyshev(usize),
+}
+
+impl fmt::Display for Adjacency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Adjacency::Four => write!(f, "4"),
+            Adjacency::Eight => write!(f, "8"),
+            Adjacency::Chebyshev(radius) => write!(f, "chebyshev:{}", radius),
+        }
+    }
+}
+
+impl Adjacency {
+    /// reads `4`, `8` or `chebyshev:<radius>`
+    pub fn parse(spec: &str) -> Result<Adjacency, Error> {
+        match spec.trim() {
+            "4" => Ok(Adjacency::Four),
+            "8" => Ok(Adjacency::Eight),
+            spec => spec
+                .strip_prefix("chebyshev:")
+                .and_then(|radius| radius.trim().parse().ok())
+                .map(Adjacency::Chebyshev)
+                .ok_or_else(|| Error::Adjacency(spec.to_string())),
+        }
+    }
+
+    /// how many rows above and below a number it can reach
+    pub fn radius(&self) -> usize {
+        match self {
+            Adjacency::Four | Adjacency::Eight => 1,
+            Adjacency::Chebyshev(radius) => *radius,
+        }
+    }
+
+    /// the tiles around `(row, col)` that a number has to cover part of to
+    /// touch it, which is the same as the tiles a number there would touch
+    pub fn cells(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        let radius = self.radius();
+        let four = *self == Adjacency::Four;
+
+        let rows = row.saturating_sub(radius)..=row + radius;
+        rows.flat_map(move |r| {
+            let cols = col.saturating_sub(radius)..=col + radius;
+            cols.map(move |c| (r, c))
+        })
+        .filter(move |&(r, c)| !four || r == row || c == col)
+    }
+
+    /// whether `number` touches the tile at `(row, col)`
+    pub fn touches(&self, number: &Number, row: usize, col: usize) -> bool {
+        match self {
+            Adjacency::Four => {
+                let beside = row == number.row && (col + 1 == number.start || col == number.end);
+                let over =
+                    row.abs_diff(number.row) == 1 && (number.start..number.end).contains(&col);
+                beside || over
+            }
+            Adjacency::Eight => number.touches(row, col),
+            Adjacency::Chebyshev(radius) => {
+                row.abs_diff(number.row) <= *radius
+                    && col + radius >= number.start
+                    && col < number.end + radius
+            }
+        }
+    }
+}
+
+/// the rules both parts are played by
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Rules {
+    pub symbols: Class,
+    pub adjacency: Adjacency,
+    /// what part 2 counts as a gear
+    pub gears: GearRule,
+}
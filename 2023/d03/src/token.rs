@@ -0,0 +1,86 @@
+//! the schematic as tokens: numbers, each with the columns it covers, and
+//! every other character that isn't a `.`, which are the symbols. it's one
+//! pass over each line, with `scan` reading the numbers alongside, and a
+//! number is over when its digits run out rather than when its value stops
+//! growing, so `0` and `007` are numbers like any other.
+//!
+//! columns count characters, not bytes, so they line up with the grid however
+//! the symbols happen to be encoded.
+
+use scan::ParseError;
+
+/// a number covering columns `start..end` of `row`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Number {
+    /// whether `(row, col)` is in the box one tile around the number, which
+    /// counts its own digits too
+    pub fn touches(&self, row: usize, col: usize) -> bool {
+        row + 1 >= self.row && row <= self.row + 1 && col + 1 >= self.start && col <= self.end
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol {
+    pub c: char,
+    pub row: usize,
+    pub col: usize,
+}
+
+/// every token in the schematic, each list in reading order
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tokens {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    /// how many rows were read
+    pub rows: usize,
+}
+
+/// reads every row up to the first blank line. whitespace counts as empty
+/// space, the same as `.`
+pub fn tokenize(binding: &str) -> Result<Tokens, ParseError> {
+    let mut tokens = Tokens::default();
+
+    for (row, line) in binding.split('\n').enumerate() {
+        if line.is_empty() {
+            break;
+        }
+        tokens.rows += 1;
+
+        // numbers come from `scan`, which hands back byte ranges. digits are
+        // all ascii, so a number is as many columns wide as it is bytes long
+        let mut numbers = scan::ints::<u32>(line).spans().peekable();
+
+        for (col, (at, c)) in line.char_indices().enumerate() {
+            if c.is_ascii_digit() {
+                if let Some((span, value)) = numbers.next_if(|(span, _)| span.start == at) {
+                    let Ok(value) = value else {
+                        return Err(ParseError::new(
+                            row,
+                            format!("the number at column {} is too big", col + 1),
+                        ));
+                    };
+                    tokens.numbers.push(Number {
+                        value,
+                        row,
+                        start: col,
+                        end: col + span.len(),
+                    });
+                }
+                continue;
+            }
+
+            if c != '.' && !c.is_whitespace() {
+                tokens.symbols.push(Symbol { c, row, col });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
@@ -0,0 +1,92 @@
+//! gear rules other than the puzzle's
+
+use aoc2023_d03::{
+    gears_with, part2, part2_with, report, rows, Count, GearRule, Overflow, Part2Error, Reduce,
+    Rules,
+};
+
+const SCHEMATIC: &str = "2.3\n.*.\n4.5\n..#\n.6.\n";
+
+fn rules(count: &str, reduce: &str) -> Rules {
+    Rules {
+        gears: GearRule {
+            count: Count::parse(count).unwrap(),
+            reduce: Reduce::parse(reduce).unwrap(),
+            ..GearRule::default()
+        },
+        ..Rules::default()
+    }
+}
+
+#[test]
+fn counts_and_reductions() {
+    // the `*` touches four numbers, so it isn't a gear by the puzzle's rules
+    assert_eq!(part2(SCHEMATIC), Ok(0));
+    assert_eq!(part2_with(SCHEMATIC, &rules("4", "product")), Ok(120));
+    assert_eq!(part2_with(SCHEMATIC, &rules("2+", "sum")), Ok(14));
+    assert_eq!(part2_with(SCHEMATIC, &rules("3-5", "max")), Ok(5));
+    assert_eq!(part2_with(SCHEMATIC, &rules("1-3", "max")), Ok(0));
+}
+
+#[test]
+fn other_symbols() {
+    let mut rules = rules("1+", "sum");
+    rules.gears.symbols = aoc2023_d03::Class::parse("[#]").unwrap();
+
+    let candidates = gears_with(SCHEMATIC, &rules).unwrap();
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].ratio, Some(Ok(11)));
+    assert_eq!(
+        candidates[0].explain(&rules.gears),
+        "# at (3, 2) touches 5, 6: sum 11"
+    );
+}
+
+#[test]
+fn bad_specs() {
+    for count in ["", "x", "2++", "4-2", "-3"] {
+        assert!(Count::parse(count).is_err(), "{}", count);
+    }
+    assert!(Reduce::parse("min").is_err());
+}
+
+// two gears worth 16 billion billion each, which is more than a u64 holds
+const HUGE: &str = "4000000000*4000000000\n.....................\n4000000000*4000000000\n";
+
+#[test]
+fn total_overflows() {
+    // each ratio fits, but their sum doesn't
+    let candidates = gears_with(HUGE, &Rules::default()).unwrap();
+    assert_eq!(candidates[0].ratio, Some(Ok(16_000_000_000_000_000_000)));
+    assert_eq!(part2(HUGE), Err(Part2Error::Overflow(Overflow)));
+    assert_eq!(rows::part2(HUGE), Err(Part2Error::Overflow(Overflow)));
+
+    assert_eq!(
+        report(HUGE, &Rules::default()).unwrap().lines().last(),
+        Some("2 of 2 candidates counted, too big for a u64 in all")
+    );
+}
+
+#[test]
+fn ratio_overflows() {
+    // one `*` touching three numbers, whose product doesn't fit
+    let schematic = "4000000000*4000000000\n4000000000...........\n";
+    let product = rules("3", "product");
+
+    let candidates = gears_with(schematic, &product).unwrap();
+    assert_eq!(candidates[0].ratio, Some(Err(Overflow)));
+    assert_eq!(
+        candidates[0].explain(&product.gears),
+        "* at (0, 10) touches 4000000000, 4000000000, 4000000000: product too big for a u64"
+    );
+    assert_eq!(
+        part2_with(schematic, &product),
+        Err(Part2Error::Overflow(Overflow))
+    );
+
+    // a sum of the same numbers is fine
+    assert_eq!(
+        part2_with(schematic, &rules("3", "sum")),
+        Ok(12_000_000_000)
+    );
+}
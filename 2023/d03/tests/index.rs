@@ -0,0 +1,61 @@
+//! the index has to agree with comparing numbers and symbols directly
+
+use aoc2023_d03::{gears_with, part_numbers_with, rows, Adjacency, Count, GearRule, Rules};
+use rng::Rng;
+
+/// a small grid with numbers and symbols scattered through it
+fn schematic(seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut out = String::new();
+    for _ in 0..12 {
+        for _ in 0..15 {
+            out.push(match rng.range(0, 7) {
+                0..=2 => (b'0' + rng.range(0, 9) as u8) as char,
+                3 => '*',
+                4 => '#',
+                _ => '.',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[test]
+fn agrees_with_rows() {
+    let adjacencies = [
+        Adjacency::Four,
+        Adjacency::Eight,
+        Adjacency::Chebyshev(0),
+        Adjacency::Chebyshev(2),
+    ];
+
+    for seed in 0..50 {
+        let binding = schematic(seed);
+        for adjacency in adjacencies {
+            let rules = Rules {
+                adjacency,
+                gears: GearRule {
+                    count: Count::AtLeast(1),
+                    ..GearRule::default()
+                },
+                ..Rules::default()
+            };
+
+            assert_eq!(
+                part_numbers_with(&binding, &rules),
+                rows::part_numbers_with(&binding, &rules),
+                "seed {}, {}",
+                seed,
+                adjacency
+            );
+            assert_eq!(
+                gears_with(&binding, &rules),
+                rows::gears_with(&binding, &rules),
+                "seed {}, {}",
+                seed,
+                adjacency
+            );
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! symbol classes and the different ideas of touching
+
+use aoc2023_d03::{part1, part1_with, Adjacency, Class, Rules};
+
+#[test]
+fn any_symbol_by_default() {
+    // `!` isn't in the puzzle's input, but it's still a symbol
+    assert_eq!(part1("12!..\n"), 12);
+}
+
+#[test]
+fn classes() {
+    let class = Class::parse("[*#a-c\\]\\-]").unwrap();
+    for c in ['*', '#', 'a', 'b', 'c', ']', '-'] {
+        assert!(class.matches(c), "{}", c);
+    }
+    for c in ['d', '+', '1', '.'] {
+        assert!(!class.matches(c), "{}", c);
+    }
+
+    let not = Class::parse("[^a-z]").unwrap();
+    assert!(not.matches('+') && !not.matches('q') && !not.matches('7'));
+
+    assert!(Class::parse("*#").is_err());
+    assert!(Class::parse("[z-a]").is_err());
+}
+
+#[test]
+fn adjacency() {
+    // a symbol on the diagonal, and one two columns off the end
+    let diagonal = "12.\n..*\n";
+    let far = "12.#\n";
+    let rules = |adjacency| Rules {
+        adjacency,
+        ..Rules::default()
+    };
+
+    assert_eq!(part1_with(diagonal, &rules(Adjacency::Four)), Ok(0));
+    assert_eq!(part1_with(diagonal, &rules(Adjacency::Eight)), Ok(12));
+    assert_eq!(part1_with(far, &rules(Adjacency::Eight)), Ok(0));
+    assert_eq!(part1_with(far, &rules(Adjacency::Chebyshev(1))), Ok(0));
+    assert_eq!(part1_with(far, &rules(Adjacency::Chebyshev(2))), Ok(12));
+
+    assert_eq!(Adjacency::parse("chebyshev:3"), Ok(Adjacency::Chebyshev(3)));
+    assert!(Adjacency::parse("6").is_err());
+}
@@ -0,0 +1,59 @@
+//! the tokenizer finds numbers by where their digits stop, not by their value
+
+use aoc2023_d03::{
+    part1, part1_with, part2_with, report, rows, tokenize, Number, Part2Error, Rules, Symbol,
+};
+
+#[test]
+fn zeros() {
+    let tokens = tokenize("0*007.\n..0...\n").unwrap();
+    let spans = tokens
+        .numbers
+        .iter()
+        .map(|number| (number.value, number.row, number.start, number.end))
+        .collect::<Vec<_>>();
+    assert_eq!(spans, [(0, 0, 0, 1), (7, 0, 2, 5), (0, 1, 2, 3)]);
+    assert_eq!(
+        tokens.symbols,
+        [Symbol {
+            c: '*',
+            row: 0,
+            col: 1
+        }]
+    );
+
+    // `007` counts as three columns wide, so it reaches the `#` below it
+    assert_eq!(part1(".....\n007..\n...#.\n"), 7);
+}
+
+#[test]
+fn columns_are_characters() {
+    let tokens = tokenize("é12\n").unwrap();
+    assert_eq!(
+        tokens.numbers,
+        [Number {
+            value: 12,
+            row: 0,
+            start: 1,
+            end: 3
+        }]
+    );
+}
+
+#[test]
+fn too_big() {
+    let schematic = "*.\n..99999999999\n";
+    let err = tokenize(schematic).unwrap_err();
+    assert_eq!(err.to_string(), "line 2: the number at column 3 is too big");
+
+    // and every solver hands the same error back rather than panicking
+    let rules = Rules::default();
+    assert_eq!(part1_with(schematic, &rules), Err(err.clone()));
+    assert_eq!(rows::part1(schematic), Err(err.clone()));
+    assert_eq!(
+        part2_with(schematic, &rules),
+        Err(Part2Error::Parse(err.clone()))
+    );
+    assert_eq!(rows::part2(schematic), Err(Part2Error::Parse(err.clone())));
+    assert_eq!(report(schematic, &rules), Err(err));
+}
@@ -0,0 +1,5 @@
+fn main() {
+    let binding = std::fs::read_to_string("input.txt").unwrap();
+
+    println!("{}", aoc2023_d04::part1(&binding));
+}
@@ -0,0 +1,160 @@
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+use scan::{LineReader, ParseError, StreamError};
+
+/// a card's winning numbers and the numbers we chose
+pub type Card = (Vec<u32>, Vec<u32>);
+
+/// splits a card into its winning numbers and the numbers we chose
+fn parse_card(idx: usize, line: &str) -> Result<Card, ParseError> {
+    let Some((numbers, chosen)) = line.split_once('|') else {
+        return Err(ParseError::new(
+            idx,
+            "missing `|` before the chosen numbers",
+        ));
+    };
+    let Some((_, winners)) = numbers.split_once(':') else {
+        return Err(ParseError::new(idx, "missing `:` after the card id"));
+    };
+
+    let winners = scan::all::<u32>(winners).map_err(|err| err.on_line(idx))?;
+    let chosen = scan::all::<u32>(chosen).map_err(|err| err.on_line(idx))?;
+
+    Ok((winners, chosen))
+}
+
+/// every card and the index of its line, in order, skipping blank lines
+fn cards(binding: &str) -> Result<Vec<(usize, Card)>, ParseError> {
+    let input = binding.split('\n').collect::<Vec<&str>>();
+
+    let mut cards = Vec::new();
+
+    for (idx, line) in input.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        cards.push((idx, parse_card(idx, line)?));
+    }
+
+    Ok(cards)
+}
+
+/// every card, in order, skipping blank lines
+pub fn parse(binding: &str) -> Result<Vec<Card>, ParseError> {
+    Ok(cards(binding)?.into_iter().map(|(_, card)| card).collect())
+}
+
+/// how many of the chosen numbers are winners
+fn matches((winners, mut chosen): Card) -> usize {
+    chosen.retain(|choice| winners.contains(choice));
+    chosen.len()
+}
+
+/// the error for the card on line `idx`, once the answer's too big for a u64
+fn too_big(idx: usize) -> ParseError {
+    ParseError::new(idx, "the answer is too big for a u64 by this card")
+}
+
+/// what a card with `matches` winners is worth in part 1
+fn points(idx: usize, matches: usize) -> Result<u64, ParseError> {
+    match matches {
+        0 => Ok(0),
+        _ => u32::try_from(matches - 1)
+            .ok()
+            .and_then(|shift| 1_u64.checked_shl(shift))
+            .ok_or_else(|| too_big(idx)),
+    }
+}
+
+/// the copies of a card: the original and however many earlier cards won.
+/// `won` saturates rather than failing, since a card past the end of the
+/// table never gets looked at, so it's only an error once it's used
+fn copies(idx: usize, won: u64) -> Result<u64, ParseError> {
+    won.checked_add(1).ok_or_else(|| too_big(idx))
+}
+
+pub fn part1(binding: &str) -> u64 {
+    let input = cards(binding).unwrap();
+
+    let mut sum = 0_u64;
+
+    for (idx, card) in input {
+        let points = points(idx, matches(card)).unwrap();
+        sum = sum.checked_add(points).ok_or_else(|| too_big(idx)).unwrap();
+    }
+
+    sum
+}
+
+/// copies won past the end of the table are dropped, the same as when
+/// streaming
+pub fn part2(binding: &str) -> u64 {
+    let input = cards(binding).unwrap();
+
+    let mut sum = 0_u64;
+
+    // extra copies of each card won by the ones before it
+    let mut won = vec![0_u64; input.len()];
+
+    for (i, (idx, card)) in input.into_iter().enumerate() {
+        let copies = copies(idx, won[i]).unwrap();
+        sum = sum.checked_add(copies).ok_or_else(|| too_big(idx)).unwrap();
+
+        let end = (i + 1 + matches(card)).min(won.len());
+        for extra in &mut won[i + 1..end] {
+            *extra = extra.saturating_add(copies);
+        }
+    }
+
+    sum
+}
+
+/// part 1 a line at a time, for inputs too big to read in one go
+pub fn part1_stream(reader: impl BufRead) -> Result<u64, StreamError> {
+    let mut lines = LineReader::new(reader);
+    let mut sum = 0_u64;
+
+    while let Some((idx, line)) = lines.next_line()? {
+        if line.is_empty() {
+            continue;
+        }
+
+        let points = points(idx, matches(parse_card(idx, line)?))?;
+        sum = sum.checked_add(points).ok_or_else(|| too_big(idx))?;
+    }
+
+    Ok(sum)
+}
+
+/// part 2 a line at a time. a card only ever hands copies to the cards right
+/// after it, so the only thing to remember is how many extra copies each of
+/// the next few cards has won so far
+pub fn part2_stream(reader: impl BufRead) -> Result<u64, StreamError> {
+    let mut lines = LineReader::new(reader);
+    let mut sum = 0_u64;
+
+    // extra copies won by the cards after this one, nearest first
+    let mut pending: VecDeque<u64> = VecDeque::new();
+
+    while let Some((idx, line)) = lines.next_line()? {
+        if line.is_empty() {
+            continue;
+        }
+
+        let card = parse_card(idx, line)?;
+        let copies = copies(idx, pending.pop_front().unwrap_or(0))?;
+        sum = sum.checked_add(copies).ok_or_else(|| too_big(idx))?;
+
+        let matches = matches(card);
+        if pending.len() < matches {
+            pending.resize(matches, 0);
+        }
+        for won in pending.iter_mut().take(matches) {
+            *won = won.saturating_add(copies);
+        }
+    }
+
+    Ok(sum)
+}
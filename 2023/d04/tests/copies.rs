@@ -0,0 +1,74 @@
+//! cards that win copies past the end of the table, and answers that stop
+//! fitting in a u64, read whole and streamed
+
+use aoc2023_d04::{part1, part1_stream, part2, part2_stream};
+
+/// a card numbered `id` whose first `matches` chosen numbers are all winners
+fn card(id: usize, matches: usize) -> String {
+    let numbers = (1..=matches.max(1))
+        .map(|n| n.to_string())
+        .collect::<Vec<String>>()
+        .join(" ");
+    match matches {
+        0 => format!("Card {}: 1 | 2\n", id),
+        _ => format!("Card {}: {} | {}\n", id, numbers, numbers),
+    }
+}
+
+/// `n` cards that each win `matches` copies
+fn table(n: usize, matches: usize) -> String {
+    (1..=n).map(|id| card(id, matches)).collect()
+}
+
+#[test]
+fn past_the_end() {
+    // the only card wins three copies of cards that aren't there
+    let cards = "Card 1: 1 2 3 | 1 2 3\n";
+    assert_eq!(part1(cards), 4);
+    assert_eq!(part2(cards), 1);
+    assert_eq!(part1_stream(cards.as_bytes()).unwrap(), 4);
+    assert_eq!(part2_stream(cards.as_bytes()).unwrap(), 1);
+
+    // the second to last card's copies only reach the last one
+    let cards = card(1, 0) + &card(2, 5) + &card(3, 0);
+    assert_eq!(part2(&cards), 4);
+    assert_eq!(part2_stream(cards.as_bytes()).unwrap(), 4);
+}
+
+#[test]
+fn points_overflow() {
+    // 64 matches is worth 2^63, which still fits
+    let cards = card(1, 64);
+    assert_eq!(part1(&cards), 1 << 63);
+    assert_eq!(part1_stream(cards.as_bytes()).unwrap(), 1 << 63);
+
+    let err = part1_stream(card(1, 65).as_bytes()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1: the answer is too big for a u64 by this card"
+    );
+
+    // and two of the biggest that fit don't
+    let err = part1_stream(table(2, 64).as_bytes()).unwrap_err();
+    assert!(err.to_string().starts_with("line 2: "), "{}", err);
+}
+
+#[test]
+fn copies_overflow() {
+    // every card wins a copy of all the ones after it, so the copies double
+    // from card to card, and 64 cards add up to exactly `u64::MAX`
+    let cards = table(64, 63);
+    assert_eq!(part2(&cards), u64::MAX);
+    assert_eq!(part2_stream(cards.as_bytes()).unwrap(), u64::MAX);
+
+    // where a 65th card has 2^64 copies
+    let err = part2_stream(table(65, 64).as_bytes()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 65: the answer is too big for a u64 by this card"
+    );
+
+    // copies won past the end don't count towards it
+    let cards = table(64, 1000);
+    assert_eq!(part2_stream(cards.as_bytes()).unwrap(), u64::MAX);
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+/// every seed's path through the maps, starting with the seed itself and then
+/// the value after each map. a map that doesn't cover the current value leaves
+/// it where it is
+///
+/// NOTE: this expands every range into a hashmap entry per number, so it only
+/// finishes on the example
+pub fn chains(binding: &str) -> Vec<Vec<i64>> {
+    let input = binding.split('\n').collect::<Vec<&str>>();
+
+    let mut sections: Vec<Vec<&str>> = Vec::new();
+    let mut current_section: Vec<&str> = Vec::new();
+
+    // parse seeds
+    let seeds = input[0].split(':').collect::<Vec<&str>>()[1]
+        .split_whitespace()
+        .map(|x| x.parse::<i64>().unwrap())
+        .collect::<Vec<i64>>();
+
+    for line in input[1..].iter() {
+        if line.trim().is_empty() {
+            if !current_section.is_empty() {
+                sections.push(current_section.clone());
+                current_section.clear();
+            }
+        } else {
+            current_section.push(line.trim());
+        }
+    }
+
+    if !current_section.is_empty() {
+        sections.push(current_section);
+    }
+
+    let mut maps: Vec<HashMap<i64, i64>> = vec![HashMap::new(); sections.len()];
+
+    for (idx, section) in sections.iter().enumerate() {
+        for mapping in section[1..].iter() {
+            let mapping = mapping
+                .split(' ')
+                .map(|x| x.parse::<i64>().unwrap())
+                .collect::<Vec<i64>>();
+
+            for i in 0..mapping[2] {
+                maps[idx].insert(mapping[1] + i, mapping[0] + i);
+            }
+        }
+    }
+
+    let mut chains = Vec::new();
+    for seed in seeds {
+        let mut dest = seed;
+        let mut chain = vec![seed];
+
+        for map in &maps {
+            if let Some(val_ref) = map.get(&dest) {
+                dest = *val_ref;
+            }
+            chain.push(dest);
+        }
+
+        chains.push(chain);
+    }
+
+    chains
+}
+
+/// every chain as `seed -> soil -> ... -> location`, one seed per line
+pub fn explain(binding: &str) -> String {
+    chains(binding)
+        .iter()
+        .map(|chain| {
+            let chain = chain.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+            chain.join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+/// part 1 by mapping every single number in every range up front
+pub fn part1(binding: &str) -> i64 {
+    let mut min = i64::MAX;
+    for chain in chains(binding) {
+        let dest = *chain.last().unwrap();
+        if dest < min {
+            min = dest;
+        }
+    }
+
+    min
+}
@@ -0,0 +1,11 @@
+fn main() {
+    let time = std::time::Instant::now();
+
+    let binding = std::fs::read_to_string("input.txt").unwrap();
+
+    let min = aoc2023_d05::part2(&binding);
+
+    let elapsed_ms = time.elapsed().as_millis();
+    println!("\n{}", min);
+    println!("\nElapsed: {} ms", elapsed_ms);
+}
@@ -0,0 +1,114 @@
+use scan::ParseError;
+
+pub mod d01_dumb;
+
+/// a single `dest source range` line from one of the almanac's maps
+pub type Mapping = (i64, i64, i64);
+
+/// the seed list and every map section, in order
+pub type Almanac = (Vec<i64>, Vec<Vec<Mapping>>);
+
+/// every number in the almanac fits in a `u32`, so they're read as such and
+/// widened, which leaves room for `source + range` without overflowing
+fn numbers(idx: usize, line: &str) -> Result<Vec<i64>, ParseError> {
+    scan::ints::<u32>(line)
+        .map(|num| num.map(i64::from).map_err(|err| err.on_line(idx)))
+        .collect()
+}
+
+/// pulls out the seed list and every map section, in order
+pub fn parse(binding: &str) -> Result<Almanac, ParseError> {
+    let input = binding.split('\n').collect::<Vec<&str>>();
+
+    // parse seed
+    let seeds = numbers(0, input[0])?;
+
+    // parse lines into sections. a blank line ends a section, and the header
+    // line starting each one has no numbers in it
+    let mut source_dest_maps: Vec<Vec<Mapping>> = Vec::new();
+    let mut source_dest_map: Vec<Mapping> = Vec::new();
+
+    for (idx, line) in input.iter().enumerate().skip(1) {
+        if line.trim().is_empty() {
+            if !source_dest_map.is_empty() {
+                source_dest_maps.push(source_dest_map);
+                source_dest_map = Vec::new();
+            }
+            continue;
+        }
+
+        let nums = numbers(idx, line)?;
+        match nums.len() {
+            0 => {}
+            3 => source_dest_map.push((nums[0], nums[1], nums[2])),
+            _ => return Err(ParseError::new(idx, "expected `dest source range`")),
+        }
+    }
+
+    if !source_dest_map.is_empty() {
+        source_dest_maps.push(source_dest_map);
+    }
+
+    Ok((seeds, source_dest_maps))
+}
+
+/// runs a seed through every map section and returns its location
+pub fn locate(seed: i64, source_dest_maps: &[Vec<Mapping>]) -> i64 {
+    let mut curr_dest = seed;
+
+    for source_dest_map in source_dest_maps {
+        for map in source_dest_map {
+            let source_start = map.1;
+            let source_end = map.1 + map.2 - 1;
+            let dest_start = map.0;
+
+            if (curr_dest >= source_start) && (curr_dest <= source_end) {
+                curr_dest = dest_start + (curr_dest - source_start);
+                break;
+            }
+        }
+    }
+
+    curr_dest
+}
+
+pub fn part1(binding: &str) -> i64 {
+    let (seeds, source_dest_maps) = parse(binding).unwrap();
+
+    let mut min = i64::MAX;
+
+    // map seeds to destinations and store the minimum
+    for seed in seeds {
+        let curr_dest = locate(seed, &source_dest_maps);
+
+        if curr_dest < min {
+            min = curr_dest;
+        }
+    }
+
+    min
+}
+
+// NOTE: this brute forces every seed in every range, so it'll take a while on
+// the real input
+pub fn part2(binding: &str) -> i64 {
+    let (seeds, source_dest_maps) = parse(binding).unwrap();
+
+    let mut min = i64::MAX;
+
+    // map seeds to destinations and store the minimum
+    for idx in (0..seeds.len()).step_by(2) {
+        let start = seeds[idx];
+        let end = seeds[idx] + seeds[idx + 1] - 1;
+
+        for seed in start..=end {
+            let curr_dest = locate(seed, &source_dest_maps);
+
+            if curr_dest < min {
+                min = curr_dest;
+            }
+        }
+    }
+
+    min
+}
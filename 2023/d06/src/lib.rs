@@ -0,0 +1,79 @@
+use scan::ParseError;
+
+/// the number of whole ms we can hold the button for and still beat `best`,
+/// found from the roots of the quadratic
+fn ways_to_win(t: f64, b: f64) -> i64 {
+    let rt = (t.powf(2.) - 4. * b).sqrt();
+
+    let high = (t + rt) / 2.;
+    let low = (t - rt) / 2.;
+
+    high.ceil() as i64 - low.floor() as i64 - 1
+}
+
+/// reads every number on the line as if the spaces between them weren't there
+fn join_digits(idx: usize, line: &str) -> Result<u64, ParseError> {
+    let mut joined: u64 = 0;
+    for (span, num) in scan::ints::<u64>(line).spans() {
+        let num = num.map_err(|err| err.on_line(idx))?;
+        joined = 10_u64
+            .checked_pow(span.len() as u32)
+            .and_then(|shift| joined.checked_mul(shift))
+            .and_then(|joined| joined.checked_add(num))
+            .ok_or_else(|| ParseError::new(idx, "joined number overflows"))?;
+    }
+    Ok(joined)
+}
+
+/// the `Time:` and `Distance:` lines
+fn lines(binding: &str) -> Result<(&str, &str), ParseError> {
+    let input = binding.split('\n').collect::<Vec<&str>>();
+
+    if input.len() < 2 {
+        return Err(ParseError::new(input.len(), "missing distance line"));
+    }
+
+    Ok((input[0], input[1]))
+}
+
+/// every race as `(time, best distance)`
+pub fn parse(binding: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    let (time_line, best_line) = lines(binding)?;
+
+    let times = scan::all::<u64>(time_line).map_err(|err| err.on_line(0))?;
+    let best = scan::all::<u64>(best_line).map_err(|err| err.on_line(1))?;
+
+    if times.len() != best.len() {
+        return Err(ParseError::new(
+            1,
+            format!("{} times but {} distances", times.len(), best.len()),
+        ));
+    }
+
+    Ok(times.into_iter().zip(best).collect())
+}
+
+/// the single race part 2 reads, with each line's numbers joined into one
+pub fn parse_joined(binding: &str) -> Result<(u64, u64), ParseError> {
+    let (time_line, best_line) = lines(binding)?;
+
+    Ok((join_digits(0, time_line)?, join_digits(1, best_line)?))
+}
+
+pub fn part1(binding: &str) -> i64 {
+    let races = parse(binding).unwrap();
+
+    let mut mult = 1;
+
+    for (t, b) in races {
+        mult *= ways_to_win(t as f64, b as f64);
+    }
+
+    mult
+}
+
+pub fn part2(binding: &str) -> i64 {
+    let (time, best) = parse_joined(binding).unwrap();
+
+    ways_to_win(time as f64, best as f64)
+}
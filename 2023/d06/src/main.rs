@@ -0,0 +1,13 @@
+fn main() {
+    let now = std::time::Instant::now();
+    let binding = std::fs::read_to_string("input.txt").unwrap();
+
+    let diff = aoc2023_d06::part2(&binding);
+
+    let elapsed = now.elapsed();
+    println!("{}", diff);
+    println!(
+        "Elapsed: {}ms",
+        elapsed.as_secs() * 1000 + elapsed.subsec_millis() as u64
+    );
+}
@@ -0,0 +1,9 @@
+fn main() {
+    let binding = std::fs::read_to_string("input.txt").unwrap();
+
+    let hands = aoc2023_d07::part1_hands(&binding).unwrap();
+
+    print!("{}", aoc2023_d07::listing(&hands));
+
+    println!("{}", aoc2023_d07::winnings(&hands));
+}
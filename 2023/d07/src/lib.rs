@@ -0,0 +1,248 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use scan::{LineReader, ParseError, StreamError};
+
+/// a hand as `(cards, bid, rank)`, where rank 0 is high card and 6 is five of a kind
+pub type Hand<'a> = (&'a str, i32, i32);
+
+/// maps (number of pairs, number of unique cards) to the hand's rank
+fn rank_map() -> HashMap<(usize, usize), i32> {
+    let mut rank_map = HashMap::new();
+    rank_map.insert((0, 5), 0);
+    rank_map.insert((1, 4), 1);
+    rank_map.insert((2, 3), 2);
+    rank_map.insert((1, 3), 3);
+    rank_map.insert((2, 2), 4);
+    rank_map.insert((1, 2), 5);
+    rank_map.insert((1, 1), 6);
+    rank_map
+}
+
+fn parse_line(idx: usize, line: &str) -> Result<(&str, i32), ParseError> {
+    let Some((hand, bid_str)) = line.split_once(' ') else {
+        return Err(ParseError::new(idx, "missing bid"));
+    };
+    let hand = hand.trim();
+
+    // sorting compares hands card by card, so they all need the same five
+    if hand.len() != 5 || !hand.chars().all(|card| "23456789TJQKA".contains(card)) {
+        return Err(ParseError::new(idx, format!("`{}` isn't a hand", hand)));
+    }
+
+    let bid = match scan::ints::<i32>(bid_str).next() {
+        Some(bid) => bid.map_err(|err| err.on_line(idx))?,
+        None => return Err(ParseError::new(idx, "missing bid")),
+    };
+
+    Ok((hand, bid))
+}
+
+/// every hand and its bid, skipping blank lines
+pub fn parse(binding: &str) -> Result<Vec<(&str, i32)>, ParseError> {
+    let input = binding.split('\n').collect::<Vec<&str>>();
+
+    let mut hands = Vec::new();
+
+    for (idx, line) in input.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        hands.push(parse_line(idx, line)?);
+    }
+
+    Ok(hands)
+}
+
+/// every hand's cards back to back, and their bids, read a line at a time.
+/// every hand is exactly five cards, so this takes a fraction of the memory
+/// the whole input would, and `unpack` turns it back into what `parse` gives
+fn parse_stream(reader: impl BufRead) -> Result<(String, Vec<i32>), StreamError> {
+    let mut lines = LineReader::new(reader);
+    let mut cards = String::new();
+    let mut bids = Vec::new();
+
+    while let Some((idx, line)) = lines.next_line()? {
+        if line.is_empty() {
+            continue;
+        }
+
+        let (hand, bid) = parse_line(idx, line)?;
+        cards.push_str(hand);
+        bids.push(bid);
+    }
+
+    Ok((cards, bids))
+}
+
+fn unpack<'a>(cards: &'a str, bids: &[i32]) -> Vec<(&'a str, i32)> {
+    bids.iter()
+        .enumerate()
+        .map(|(idx, bid)| (&cards[5 * idx..5 * idx + 5], *bid))
+        .collect()
+}
+
+/// orders by rank first, then card by card using the position of each card in `card_arr`
+fn sort_hands(hands: &mut [Hand], card_arr: &[char]) {
+    hands.sort_by(|a, b| {
+        if a.2.cmp(&b.2) == Ordering::Equal {
+            for idx in 0..a.0.len() {
+                let a_card = a.0.chars().collect::<Vec<char>>()[idx];
+                let b_card = b.0.chars().collect::<Vec<char>>()[idx];
+
+                let a_card_rank = card_arr.iter().position(|&r| r == a_card).unwrap() + 1;
+                let b_card_rank = card_arr.iter().position(|&r| r == b_card).unwrap() + 1;
+
+                match a_card_rank.cmp(&b_card_rank) {
+                    Ordering::Equal => {}
+                    ordering => return ordering,
+                }
+            }
+        }
+
+        a.2.cmp(&b.2)
+    });
+}
+
+/// every hand, weakest first
+pub fn part1_hands(binding: &str) -> Result<Vec<Hand<'_>>, ParseError> {
+    Ok(rank1(parse(binding)?))
+}
+
+fn rank1(input: Vec<(&str, i32)>) -> Vec<Hand<'_>> {
+    let card_arr = [
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+    ];
+
+    let rank_map = rank_map();
+
+    let mut hands = Vec::new();
+
+    for (hand, bid) in input {
+        let mut uniques: HashMap<char, i32> = HashMap::new();
+        let mut pairs = HashMap::new();
+
+        for card in hand.chars() {
+            // seeing a card for the second time makes it a pair
+            if uniques.insert(card, 1).is_some() {
+                pairs.insert(card, 1);
+            }
+        }
+
+        let hand_rank = rank_map.get(&(pairs.len(), uniques.len())).unwrap();
+        hands.push((hand, bid, *hand_rank));
+    }
+
+    sort_hands(&mut hands, &card_arr);
+
+    hands
+}
+
+/// every hand with `J` played as a joker, weakest first
+pub fn part2_hands(binding: &str) -> Result<Vec<Hand<'_>>, ParseError> {
+    Ok(rank2(parse(binding)?))
+}
+
+fn rank2(input: Vec<(&str, i32)>) -> Vec<Hand<'_>> {
+    let card_arr = [
+        'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A',
+    ];
+
+    let rank_map = rank_map();
+
+    let mut hands = Vec::new();
+
+    for (hand, bid) in input {
+        let mut uniques: HashMap<char, i32> = HashMap::new();
+        let mut pairs = HashMap::new();
+        let mut joker_count = 0;
+
+        for card in hand.chars() {
+            // if the card is a joker, add to joker count and skip
+            if card == 'J' {
+                joker_count += 1;
+                continue;
+            }
+            // seeing a card for the second time makes it a pair
+            if uniques.insert(card, 1).is_some() {
+                pairs.insert(card, 1);
+            }
+        }
+
+        match rank_map.get(&(pairs.len(), uniques.len())) {
+            Some(hand_rank) => hands.push((hand, bid, *hand_rank)),
+            None => {
+                if pairs.is_empty() {
+                    if joker_count == 5 || joker_count == 4 {
+                        hands.push((hand, bid, 6));
+                    } else if joker_count == 3 {
+                        hands.push((hand, bid, 5));
+                    } else if joker_count == 2 {
+                        hands.push((hand, bid, 3));
+                    } else if joker_count == 1 {
+                        hands.push((hand, bid, 1));
+                    } else {
+                        hands.push((hand, bid, 0));
+                    }
+                } else if pairs.len() == 1 {
+                    if joker_count == 3 {
+                        hands.push((hand, bid, 6));
+                    } else if joker_count == 2 {
+                        hands.push((hand, bid, 5));
+                    } else if joker_count == 1 {
+                        hands.push((hand, bid, 3));
+                    }
+                } else if pairs.len() == 2 && joker_count == 1 {
+                    hands.push((hand, bid, 4));
+                }
+            }
+        }
+    }
+
+    sort_hands(&mut hands, &card_arr);
+
+    hands
+}
+
+/// sums each bid multiplied by the hand's position in the sorted list
+pub fn winnings(hands: &[Hand]) -> i64 {
+    let mut sum: i64 = 0;
+    for (idx, hand_tuple) in hands.iter().enumerate() {
+        sum += (idx as i64 + 1) * hand_tuple.1 as i64;
+    }
+    sum
+}
+
+/// every hand as `cards bid rank`, one per line, in the order they're given
+pub fn listing(hands: &[Hand]) -> String {
+    hands
+        .iter()
+        .map(|hand_tuple| format!("{} {} {}\n", hand_tuple.0, hand_tuple.1, hand_tuple.2))
+        .collect()
+}
+
+pub fn part1(binding: &str) -> i64 {
+    winnings(&part1_hands(binding).unwrap())
+}
+
+pub fn part2(binding: &str) -> i64 {
+    winnings(&part2_hands(binding).unwrap())
+}
+
+/// part 1 reading the input a line at a time, for inputs too big to read in
+/// one go. the hands still all have to be sorted at the end, but only their
+/// cards and bids are kept until then
+pub fn part1_stream(reader: impl BufRead) -> Result<i64, StreamError> {
+    let (cards, bids) = parse_stream(reader)?;
+
+    Ok(winnings(&rank1(unpack(&cards, &bids))))
+}
+
+/// part 2 reading the input a line at a time, as in `part1_stream`
+pub fn part2_stream(reader: impl BufRead) -> Result<i64, StreamError> {
+    let (cards, bids) = parse_stream(reader)?;
+
+    Ok(winnings(&rank2(unpack(&cards, &bids))))
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use scan::ParseError;
+
+pub mod p01_regex;
+
+/// a node as `(label, left, right)`
+pub type Node<'a> = (&'a str, &'a str, &'a str);
+
+/// the instructions split into single `L`/`R` strings, every node in the
+/// order it's defined, and a lookup from each node's label to its index
+pub type Network<'a> = (Vec<&'a str>, Vec<Node<'a>>, HashMap<&'a str, usize>);
+
+pub fn parse_line(line: &str) -> Option<Node<'_>> {
+    // XXX = (YYY, ZZZ)
+    let (label, rest) = line.split_once(" = (")?;
+    let (left, right) = rest.strip_suffix(')')?.split_once(", ")?;
+
+    Some((label, left, right))
+}
+
+pub fn parse(binding: &str) -> Result<Network<'_>, ParseError> {
+    let input = binding.split('\n').collect::<Vec<&str>>();
+
+    let instructions = input[0].trim().split("").collect::<Vec<&str>>();
+    // remove first and last instruction
+    let instructions = instructions[1..(instructions.len() - 1)].to_vec();
+
+    if instructions.is_empty() {
+        return Err(ParseError::new(0, "no instructions"));
+    }
+    if let Some(other) = instructions.iter().find(|x| **x != "L" && **x != "R") {
+        return Err(ParseError::new(0, format!("`{}` isn't a turn", other)));
+    }
+
+    let mut nodes = Vec::new();
+    let mut lines = Vec::new();
+    let mut lookup: HashMap<&str, usize> = HashMap::new();
+
+    for (idx, line) in input.iter().enumerate().skip(2) {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let Some(node) = parse_line(line) else {
+            return Err(ParseError::new(idx, "expected `XXX = (YYY, ZZZ)`"));
+        };
+
+        lookup.insert(node.0, nodes.len());
+        nodes.push(node);
+        lines.push(idx);
+    }
+
+    // walking relies on every turn leading to a node that exists
+    for ((_, left, right), idx) in nodes.iter().zip(lines) {
+        if let Some(missing) = [left, right].iter().find(|x| !lookup.contains_key(**x)) {
+            return Err(ParseError::new(
+                idx,
+                format!("no node called `{}`", missing),
+            ));
+        }
+    }
+
+    Ok((instructions, nodes, lookup))
+}
+
+/// counts the steps from `start` until we land on a node matching `done`
+fn walk(
+    instructions: &[&str],
+    nodes: &[Node],
+    lookup: &HashMap<&str, usize>,
+    start: &str,
+    done: fn(&str) -> bool,
+) -> usize {
+    let mut count = 0;
+    let mut current_index = lookup.get(start).unwrap();
+
+    loop {
+        let instruction = instructions[count % instructions.len()];
+
+        let (label, left, right) = nodes[*current_index];
+
+        if done(label) {
+            break;
+        }
+
+        count += 1;
+        if instruction == "L" {
+            current_index = lookup.get(left).unwrap();
+        } else if instruction == "R" {
+            current_index = lookup.get(right).unwrap();
+        }
+    }
+
+    count
+}
+
+pub fn part1(binding: &str) -> usize {
+    let (instructions, nodes, lookup) = parse(binding).unwrap();
+
+    walk(&instructions, &nodes, &lookup, "AAA", |label| {
+        label == "ZZZ"
+    })
+}
+
+pub fn part2(binding: &str) -> usize {
+    let (instructions, nodes, lookup) = parse(binding).unwrap();
+
+    let mut currents = lookup
+        .keys()
+        .filter(|label| label.ends_with('A'))
+        .copied()
+        .collect::<Vec<&str>>();
+    // keep the walks in the order they show up in the input
+    currents.sort_by_key(|label| lookup[label]);
+
+    let counts = currents
+        .iter()
+        .map(|current| {
+            walk(&instructions, &nodes, &lookup, current, |label| {
+                label.ends_with('Z')
+            })
+        })
+        .collect::<Vec<usize>>();
+
+    let mut lcm = counts[0];
+    for count in counts.iter().skip(1) {
+        lcm = num::integer::lcm(lcm, *count);
+    }
+
+    lcm
+}
@@ -0,0 +1,10 @@
+fn main() {
+    let time = std::time::Instant::now();
+
+    let binding = std::fs::read_to_string("input.txt").unwrap();
+
+    let count = aoc2023_d08::part1(&binding);
+
+    println!("Time: {}ms", time.elapsed().as_millis());
+    println!("Count: {}", count);
+}
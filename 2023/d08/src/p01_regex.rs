@@ -10,15 +10,15 @@ fn parse_line(line: &str, regex: regex::Regex) -> (&str, &str, &str) {
     let left = caps.name("left").unwrap().as_str();
     let right = caps.name("right").unwrap().as_str();
 
-    return (label, left, right);
+    (label, left, right)
 }
 
-fn main() {
-    let time = std::time::Instant::now();
+/// part 1 as it was first written, reading every node with a regex each time
+/// it's visited
+pub fn part1(binding: &str) -> usize {
     let node_re = Regex::new(r"(?<label>\w+) = \((?<left>\w+), (?<right>\w+)\)").unwrap();
 
-    let binding = std::fs::read_to_string("input.txt").unwrap();
-    let input = binding.split("\n").collect::<Vec<&str>>();
+    let input = binding.split('\n').collect::<Vec<&str>>();
 
     let instructions = input[0].trim().split("").collect::<Vec<&str>>();
     // remove first and last instruction
@@ -26,10 +26,8 @@ fn main() {
 
     let mut nodes: HashMap<&str, usize> = HashMap::new();
 
-    for idx in 2..(input.len() - 1) {
-        let line = input[idx].trim();
-
-        let (label, left, right) = parse_line(line, node_re.clone());
+    for (idx, line) in input.iter().enumerate().take(input.len() - 1).skip(2) {
+        let (label, _, _) = parse_line(line.trim(), node_re.clone());
 
         nodes.insert(label, idx);
     }
@@ -44,27 +42,19 @@ fn main() {
 
         let (label, left, right) = parse_line(line, node_re.clone());
 
-        // println!("CURRENT LINE: {} -> ({} {})", line, left, right);
-
         if label == "ZZZ" {
-            // println!("Found ZZZ at index: {}", current_index);
             break;
         }
 
         count += 1;
         if instruction == "L" {
-            // println!("moving left from {} to {}", current, left);
             current = left;
             current_index = nodes.get(current).unwrap();
         } else if instruction == "R" {
-            // println!("moving right from {} to {}", current, right);
             current = right;
             current_index = nodes.get(current).unwrap();
         }
-
-        // println!("");
     }
 
-    println!("Time: {}ms", time.elapsed().as_millis());
-    println!("Count: {}", count);
+    count
 }
@@ -0,0 +1,9 @@
+fn main() {
+    let time = std::time::Instant::now();
+    let binding = std::fs::read_to_string("input.txt").unwrap();
+
+    let sum = aoc2023_d09::part1(&binding);
+
+    println!("Sum: {}", sum);
+    println!("Time: {}us", time.elapsed().as_micros());
+}
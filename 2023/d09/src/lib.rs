@@ -0,0 +1,162 @@
+use std::io::BufRead;
+
+use scan::{LineReader, ParseError, StreamError};
+
+/// every sequence and the index of its line, skipping blank lines
+fn sequences(binding: &str) -> Result<Vec<(usize, Vec<i64>)>, ParseError> {
+    let input = binding.split('\n').collect::<Vec<&str>>();
+
+    let mut sequences = Vec::new();
+
+    for (idx, line) in input.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let sequence = scan::all::<i64>(line).map_err(|err| err.on_line(idx))?;
+        sequences.push((idx, sequence));
+    }
+
+    Ok(sequences)
+}
+
+/// every sequence, skipping blank lines
+pub fn parse(binding: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    Ok(sequences(binding)?
+        .into_iter()
+        .map(|(_, sequence)| sequence)
+        .collect())
+}
+
+/// lays a sequence out as the first row of the difference table
+fn table(split_line: &[i64]) -> Vec<Vec<Option<i64>>> {
+    let mut dp: Vec<Vec<Option<i64>>> = vec![vec![None; split_line.len()]; split_line.len()];
+
+    for (i, val) in split_line.iter().enumerate() {
+        dp[0][i] = Some(*val);
+    }
+
+    dp
+}
+
+/// the value that comes after a sequence, or `None` if a difference or the
+/// value itself doesn't fit in an i64
+fn next(sequence: &[i64]) -> Option<i64> {
+    let mut dp = table(sequence);
+    let len = dp.len();
+
+    // indexing via arr[n]: nth derivative of function
+    // indexing via arr[n][m]: value of f^n(m)
+
+    for deriv in 1..len {
+        for val in (deriv..len).rev() {
+            let diff = dp[deriv - 1][val]
+                .unwrap()
+                .checked_sub(dp[deriv - 1][val - 1].unwrap())?;
+            dp[deriv][val] = Some(diff);
+        }
+    }
+
+    let mut next = 0_i64;
+
+    for row in dp.iter() {
+        match row[row.len() - 1] {
+            Some(val) => next = next.checked_add(val)?,
+            None => break,
+        }
+    }
+
+    Some(next)
+}
+
+/// the value that comes before a sequence, or `None` if a difference or the
+/// value itself doesn't fit in an i64
+fn prev(sequence: &[i64]) -> Option<i64> {
+    let mut dp = table(sequence);
+    let len = dp.len();
+
+    // indexing via arr[n]: nth derivative of function
+    // indexing via arr[n][m]: value of f^n(m)
+
+    for deriv in 1..len {
+        for val in 0..(len - deriv) {
+            let diff = dp[deriv - 1][val]
+                .unwrap()
+                .checked_sub(dp[deriv - 1][val + 1].unwrap())?;
+            dp[deriv][val] = Some(diff);
+        }
+    }
+
+    let mut next = 0_i64;
+
+    for row in dp.iter() {
+        match row[0] {
+            Some(val) => next = next.checked_add(val)?,
+            None => break,
+        }
+    }
+
+    Some(next)
+}
+
+/// adds the value extrapolated from line `idx` to `sum`, failing on that line
+/// if either stops fitting in an i64
+fn add(idx: usize, sum: i64, value: Option<i64>) -> Result<i64, ParseError> {
+    value
+        .and_then(|value| sum.checked_add(value))
+        .ok_or_else(|| ParseError::new(idx, "the answer is too big for an i64 by this line"))
+}
+
+/// sums `extrapolate` over every sequence
+fn sum(binding: &str, extrapolate: fn(&[i64]) -> Option<i64>) -> Result<i64, ParseError> {
+    let mut sum = 0;
+    for (idx, sequence) in sequences(binding)? {
+        sum = add(idx, sum, extrapolate(&sequence))?;
+    }
+
+    Ok(sum)
+}
+
+/// sums `extrapolate` over every sequence from `reader`, without holding more
+/// than a line in memory
+fn stream(
+    reader: impl BufRead,
+    extrapolate: fn(&[i64]) -> Option<i64>,
+) -> Result<i64, StreamError> {
+    let mut lines = LineReader::new(reader);
+    let mut sum = 0;
+    let mut sequence = Vec::new();
+
+    while let Some((idx, line)) = lines.next_line()? {
+        if line.is_empty() {
+            continue;
+        }
+
+        sequence.clear();
+        for val in scan::ints::<i64>(line) {
+            sequence.push(val.map_err(|err| err.on_line(idx))?);
+        }
+
+        sum = add(idx, sum, extrapolate(&sequence))?;
+    }
+
+    Ok(sum)
+}
+
+pub fn part1(binding: &str) -> i64 {
+    sum(binding, next).unwrap()
+}
+
+pub fn part2(binding: &str) -> i64 {
+    sum(binding, prev).unwrap()
+}
+
+/// part 1 a line at a time, for inputs too big to read in one go
+pub fn part1_stream(reader: impl BufRead) -> Result<i64, StreamError> {
+    stream(reader, next)
+}
+
+/// part 2 a line at a time, for inputs too big to read in one go
+pub fn part2_stream(reader: impl BufRead) -> Result<i64, StreamError> {
+    stream(reader, prev)
+}
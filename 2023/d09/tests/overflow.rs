@@ -0,0 +1,34 @@
+//! values past an i32, and differences past an i64, read whole and streamed
+
+use aoc2023_d09::{part1, part1_stream, part2, part2_stream};
+
+#[test]
+fn past_an_i32() {
+    // a line whose values, and both ends' extrapolations, need 64 bits
+    let line = "3000000000 6000000000 9000000000\n";
+    assert_eq!(part1(line), 12_000_000_000);
+    assert_eq!(part2(line), 0);
+    assert_eq!(part1_stream(line.as_bytes()).unwrap(), 12_000_000_000);
+    assert_eq!(part2_stream(line.as_bytes()).unwrap(), 0);
+}
+
+#[test]
+fn past_an_i64() {
+    // the values fit, but the difference between them doesn't
+    let lines = "1 2 3\n9223372036854775807 -9223372036854775808\n";
+    let err = part1_stream(lines.as_bytes()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2: the answer is too big for an i64 by this line"
+    );
+    assert!(part2_stream(lines.as_bytes()).is_err());
+
+    // and a sum that stops fitting part way down
+    let lines = "9223372036854775807\n1\n";
+    let err = part1_stream(lines.as_bytes()).unwrap_err();
+    assert!(err.to_string().starts_with("line 2: "), "{}", err);
+
+    // a number that doesn't fit at all is the parser's to report
+    let err = part1_stream("1 99999999999999999999\n".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), "line 1: integer at bytes 2..22 overflows");
+}
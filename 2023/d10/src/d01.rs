@@ -0,0 +1,7 @@
+fn main() {
+    let binding = std::fs::read_to_string("input.txt").unwrap();
+
+    let walk = aoc2023_d10::walk(&binding).unwrap();
+
+    println!("furthest point is {} steps away", walk.path.len() / 2);
+}
@@ -0,0 +1,19 @@
+use aoc2023_d10::Tile;
+
+fn main() {
+    let binding = std::fs::read_to_string("input.txt").unwrap();
+
+    let walk = aoc2023_d10::walk(&binding).unwrap();
+
+    let area = aoc2023_d10::classify(&walk)
+        .iter()
+        .flatten()
+        .filter(|tile| **tile == Tile::Inside)
+        .count();
+
+    println!(
+        "furthest point is {}; area is {}",
+        walk.path.len() / 2,
+        area
+    );
+}
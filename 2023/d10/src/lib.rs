@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+
+use scan::ParseError;
+
+/// a tile's place relative to the loop, as found by the `within` scan
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Loop,
+    Inside,
+    Outside,
+}
+
+/// the result of following the pipe loop from `S` back around to itself
+pub struct Walk<'a> {
+    pub grid: Vec<Vec<&'a str>>,
+    /// position of `S` as (row, col)
+    pub start: (usize, usize),
+    /// every tile stepped on, in order. the last entry is `S` again
+    pub path: Vec<(usize, usize)>,
+    /// for each row, the columns that are part of the loop
+    pub relevant: Vec<Vec<usize>>,
+    /// whether `S` stands in for a pipe that connects downwards
+    pub s_vert: bool,
+}
+
+/// splits the input into a grid of single character strings
+pub fn parse(binding: &str) -> Vec<Vec<&str>> {
+    binding
+        .lines()
+        .map(|x| {
+            x.trim()
+                .split("")
+                .filter(|c| !c.is_empty())
+                .collect::<Vec<&str>>()
+        })
+        .collect::<Vec<Vec<&str>>>()
+}
+
+pub fn walk(binding: &str) -> Result<Walk<'_>, ParseError> {
+    let input = parse(binding);
+
+    let mut relevant: Vec<Vec<usize>> = vec![];
+
+    //find first "S" in binding
+    let mut start = None;
+
+    for (index, row) in input.iter().enumerate() {
+        relevant.push(vec![]);
+        for (index2, col) in row.iter().enumerate() {
+            if col == &"S" {
+                if start.is_some() {
+                    return Err(ParseError::new(index, "more than one `S`"));
+                }
+                start = Some((index, index2));
+                relevant[index].push(index2);
+            }
+        }
+    }
+
+    let Some(start) = start else {
+        return Err(ParseError::new(input.len(), "no `S` to start from"));
+    };
+    let (mut i, mut j) = start;
+
+    // anything off the edge of the grid reads as ground
+    let at = |i: usize, j: usize| -> &str {
+        input
+            .get(i)
+            .and_then(|row| row.get(j))
+            .copied()
+            .unwrap_or(".")
+    };
+
+    // 0: N, 1: E, 2: S, 3: W
+    let mut direction = 4;
+    let mut s_vert = false;
+
+    let (up, down) = (at(i.wrapping_sub(1), j), at(i + 1, j));
+    let (left, right) = (at(i, j.wrapping_sub(1)), at(i, j + 1));
+
+    if up == "|" || up == "F" || up == "7" {
+        direction = 0;
+        if down == "|" || down == "J" || down == "L" {
+            s_vert = true;
+        }
+    } else if right == "-" || right == "J" || right == "7" {
+        direction = 1;
+        if down == "|" || down == "J" || down == "L" {
+            s_vert = true;
+        }
+    } else if down == "|" || down == "L" || down == "J" {
+        direction = 2;
+        if up == "|" || up == "J" || up == "L" {
+            s_vert = true;
+        }
+    } else if left == "-" || left == "F" || left == "L" {
+        direction = 3;
+        if down == "|" || down == "J" || down == "L" {
+            s_vert = true;
+        }
+    }
+
+    if direction == 4 {
+        return Err(ParseError::new(i, "`S` doesn't connect to any pipe"));
+    }
+
+    let mut mover = HashMap::new();
+    mover.insert("F", [1, 4, 4, 2]);
+    mover.insert("7", [3, 2, 4, 4]);
+    mover.insert("L", [4, 4, 1, 0]);
+    mover.insert("J", [4, 0, 3, 4]);
+    mover.insert("|", [0, 4, 2, 4]);
+    mover.insert("-", [4, 1, 4, 3]);
+
+    let mut path = Vec::new();
+    loop {
+        let from = i;
+        match direction {
+            0 => i = i.wrapping_sub(1),
+            1 => j += 1,
+            2 => i += 1,
+            3 => j = j.wrapping_sub(1),
+            _ => panic!("invalid direction"),
+        }
+
+        let Some(pipe) = input.get(i).and_then(|row| row.get(j)) else {
+            return Err(ParseError::new(from, "the loop runs off the grid"));
+        };
+
+        path.push((i, j));
+        relevant[i].push(j);
+
+        if *pipe == "S" {
+            break;
+        }
+
+        // following the loop only ever leads back to `S` if every pipe on
+        // it connects to the one before it
+        direction = match mover.get(pipe) {
+            Some(turns) if turns[direction] != 4 => turns[direction],
+            _ => {
+                return Err(ParseError::new(
+                    i,
+                    format!("`{}` at column {} doesn't connect to the loop", pipe, j + 1),
+                ))
+            }
+        };
+    }
+
+    Ok(Walk {
+        grid: input,
+        start,
+        path,
+        relevant,
+        s_vert,
+    })
+}
+
+/// scans each row left to right, flipping `within` every time we cross a
+/// pipe in the loop that connects downwards
+pub fn classify(walk: &Walk) -> Vec<Vec<Tile>> {
+    let input = &walk.grid;
+    let mut tiles = Vec::new();
+
+    for (idx, line) in input.iter().enumerate() {
+        let mut row = Vec::new();
+        let mut within = false;
+        for (jdx, pipe) in line.iter().enumerate() {
+            if walk.relevant[idx].contains(&jdx) {
+                let pipe = *pipe;
+                if pipe == "|" || pipe == "7" || pipe == "F" || (pipe == "S" && walk.s_vert) {
+                    within = !within;
+                }
+                row.push(Tile::Loop);
+            } else if within {
+                row.push(Tile::Inside);
+            } else {
+                row.push(Tile::Outside);
+            }
+        }
+        tiles.push(row);
+    }
+
+    tiles
+}
+
+/// swaps the pipe letters out for box drawing characters so the loop is easier to follow
+pub fn pipe(tile: &str) -> char {
+    match tile {
+        "|" => '│',
+        "-" => '─',
+        "F" => '┌',
+        "7" => '┐',
+        "L" => '└',
+        "J" => '┘',
+        _ => tile.chars().next().unwrap_or(' '),
+    }
+}
+
+/// draws the grid with the loop in box drawing characters, every tile inside
+/// it as `I`, and everything else as `.`
+pub fn render(walk: &Walk) -> String {
+    let mut out = String::new();
+
+    for (row, tiles) in walk.grid.iter().zip(classify(walk)) {
+        for (tile, kind) in row.iter().zip(tiles) {
+            out.push(match kind {
+                Tile::Loop => pipe(tile),
+                Tile::Inside => 'I',
+                Tile::Outside => '.',
+            });
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// the furthest point from `S` is halfway around the loop
+pub fn part1(binding: &str) -> usize {
+    walk(binding).unwrap().path.len() / 2
+}
+
+pub fn part2(binding: &str) -> usize {
+    classify(&walk(binding).unwrap())
+        .iter()
+        .flatten()
+        .filter(|tile| **tile == Tile::Inside)
+        .count()
+}
@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+fn main() {
+    let crate_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+
+    // everything cbindgen reads: the crate's sources, its manifest, and its
+    // own config
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let config = cbindgen::Config::from_root_or_default(&crate_dir);
+
+    // the checked-in copy in include/ is only ever written on purpose; see
+    // tests/header.rs
+    cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("couldn't generate the C header")
+        .write_to_file(out_dir.join("aoc.h"));
+}
@@ -0,0 +1,168 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* generated by build.rs with cbindgen and checked by tests/header.rs, don't edit by hand */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * bumped whenever the C interface changes in an incompatible way
+ */
+#define AOC_ABI_VERSION 1
+
+/**
+ * the answer was written to `out_buf`
+ */
+#define AOC_OK 0
+
+/**
+ * `input_ptr` or `out_buf` was null
+ */
+#define AOC_ERR_NULL -1
+
+/**
+ * there's no solver for the requested year, day, and part
+ */
+#define AOC_ERR_UNKNOWN -2
+
+/**
+ * the input wasn't valid utf-8
+ */
+#define AOC_ERR_UTF8 -3
+
+/**
+ * the solver panicked, most likely on malformed input
+ */
+#define AOC_ERR_PANIC -4
+
+/**
+ * `out_buf` is too small to hold the answer and its trailing nul
+ */
+#define AOC_ERR_BUFFER -5
+
+/**
+ * the input isn't in the shape the puzzle says it should be
+ */
+#define AOC_ERR_INPUT -6
+
+/**
+ * there's no answer to give, like one too big for the solver's type
+ */
+#define AOC_ERR_ANSWER -7
+
+/**
+ * bumped whenever the plugin interface changes in an incompatible way
+ */
+#define AOC_PLUGIN_ABI_VERSION 1
+
+/**
+ * solves a part for the `len` bytes of utf-8 input at `input_ptr`, with the
+ * day's settings from aoc.toml as the `options_len` bytes of toml at
+ * `options_ptr`. follows the same rules as `aoc_solve`: write a
+ * nul-terminated answer to `out_buf` and return `AOC_OK`, or return one of
+ * the `AOC_ERR_*` codes. the runner retries with a bigger buffer on
+ * `AOC_ERR_BUFFER`.
+ */
+typedef int32_t (*AocPluginSolve)(const uint8_t *input_ptr,
+                                  size_t len,
+                                  const uint8_t *options_ptr,
+                                  size_t options_len,
+                                  uint8_t *out_buf,
+                                  size_t out_len);
+
+/**
+ * one part of one day that a plugin can solve. it's run as a variant called
+ * `name`, so pick names that don't clash with the day's own variants
+ */
+typedef struct AocPluginSolver {
+  uint32_t year;
+  uint32_t day;
+  uint32_t part;
+  /**
+   * nul-terminated utf-8
+   */
+  const char *name;
+  AocPluginSolve solve;
+} AocPluginSolver;
+
+/**
+ * what a plugin registers with the runner.
+ *
+ * a plugin is a shared library exporting
+ *
+ * ```c
+ * const AocPlugin *aoc_plugin_register(uint32_t abi_version);
+ * ```
+ *
+ * which is called once, with the runner's `AOC_PLUGIN_ABI_VERSION`, when the
+ * plugin is loaded. it should return null if it can't speak that version,
+ * and otherwise a pointer that, along with everything it points to, stays
+ * valid until the library is unloaded.
+ */
+typedef struct AocPlugin {
+  /**
+   * the `AOC_PLUGIN_ABI_VERSION` the plugin was built against
+   */
+  uint32_t abi_version;
+  /**
+   * nul-terminated utf-8, shown next to its solvers in `aoc list`
+   */
+  const char *name;
+  const struct AocPluginSolver *solvers;
+  size_t solver_count;
+} AocPlugin;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * returns the `AOC_ABI_VERSION` the library was built with
+ */
+uint32_t aoc_abi_version(void);
+
+/**
+ * solves `part` of `day` from 2023 for the `len` bytes of utf-8 input at
+ * `input_ptr`.
+ *
+ * on success, the answer is written to `out_buf` as a nul-terminated string
+ * and `AOC_OK` is returned. otherwise one of the negative `AOC_ERR_*` codes
+ * is returned and `out_buf` is left untouched.
+ *
+ * # Safety
+ *
+ * `input_ptr` must point to `len` readable bytes, and `out_buf` must point to
+ * `out_len` writable bytes.
+ */
+int32_t aoc_solve(uint32_t day,
+                  uint32_t part,
+                  const uint8_t *input_ptr,
+                  size_t len,
+                  uint8_t *out_buf,
+                  size_t out_len);
+
+/**
+ * the same as `aoc_solve`, but for a puzzle from any year.
+ *
+ * # Safety
+ *
+ * `input_ptr` must point to `len` readable bytes, and `out_buf` must point to
+ * `out_len` writable bytes.
+ */
+int32_t aoc_solve_year(uint32_t year,
+                       uint32_t day,
+                       uint32_t part,
+                       const uint8_t *input_ptr,
+                       size_t len,
+                       uint8_t *out_buf,
+                       size_t out_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC_H */
@@ -0,0 +1,360 @@
+//! settings from `aoc.toml`. the one at the root of the repo holds the
+//! team's defaults; a user-level one at `~/.config/aoc/aoc.toml` (or under
+//! `$XDG_CONFIG_HOME`) is layered on top of it, so each machine can override
+//! whatever it needs. every setting is optional.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::{root, OptionError, Options};
+
+/// how `aoc run` prints its answers
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Output {
+    /// `2023 day 1 part 1: 142 (12us)`
+    #[default]
+    Text,
+    /// one json object per answer
+    Json,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// where inputs are read from, as `<input_dir>/<year>/dNN/input.txt`.
+    /// relative paths are relative to the root of the repo
+    pub input_dir: PathBuf,
+    pub output: Output,
+    /// how many times `aoc bench` runs each part
+    pub bench_iterations: usize,
+    /// any part that takes longer than this gets a warning
+    pub time_budget_ms: Option<u64>,
+    /// the file holding the adventofcode.com session cookie, for tools that
+    /// download inputs. see `session`
+    pub session_file: PathBuf,
+    /// where plugins are loaded from. see `plugins`
+    pub plugin_dir: PathBuf,
+    /// per-day settings, under `[days.<year>.dNN]`
+    pub days: BTreeMap<String, BTreeMap<String, DayConfig>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DayConfig {
+    /// the variant to run in place of the regular solver, for the parts it
+    /// covers
+    pub variant: Option<String>,
+    /// everything else is handed to the day's solvers as is
+    #[serde(flatten)]
+    pub options: Options,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            input_dir: root(),
+            output: Output::Text,
+            bench_iterations: 10,
+            time_budget_ms: None,
+            session_file: user_dir().unwrap_or_default().join("session"),
+            plugin_dir: user_dir().unwrap_or_default().join("plugins"),
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(path, err) => write!(f, "couldn't read {}: {}", path.display(), err),
+            Error::Parse(path, err) => write!(f, "{}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// where the user-level config, and anything else that's per machine, lives
+pub fn user_dir() -> Option<PathBuf> {
+    let base = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(base.join("aoc"))
+}
+
+/// every config file that's read, lowest precedence first
+pub fn paths() -> Vec<PathBuf> {
+    let mut paths = vec![root().join("aoc.toml")];
+    paths.extend(user_dir().map(|dir| dir.join("aoc.toml")));
+    paths
+}
+
+/// copies `layer` over `base`, merging tables key by key
+fn merge(base: &mut toml::Table, layer: toml::Table) {
+    for (key, value) in layer {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(layer)) => merge(base, layer),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), std::env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+impl Config {
+    /// reads every config file that exists, falling back to the defaults for
+    /// anything none of them set
+    pub fn load() -> Result<Config, Error> {
+        Config::load_from(&paths())
+    }
+
+    /// like `load`, from the given files, lowest precedence first
+    pub fn load_from(paths: &[PathBuf]) -> Result<Config, Error> {
+        let mut merged = toml::Table::new();
+
+        for path in paths {
+            let text = match fs::read_to_string(path) {
+                Ok(text) => text,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(Error::Io(path.clone(), err)),
+            };
+
+            // check each file on its own, so mistakes are blamed on the
+            // right one
+            let table = text
+                .parse::<toml::Table>()
+                .map_err(|err| Error::Parse(path.clone(), err))?;
+            Config::deserialize(table.clone()).map_err(|err| Error::Parse(path.clone(), err))?;
+
+            merge(&mut merged, table);
+        }
+
+        let mut config = Config::deserialize(merged).expect("every layer was already checked");
+        config.input_dir = root().join(expand_home(&config.input_dir));
+        config.session_file = root().join(expand_home(&config.session_file));
+        config.plugin_dir = root().join(expand_home(&config.plugin_dir));
+
+        Ok(config)
+    }
+
+    /// where a day's plain text input is read from
+    pub fn input_path(&self, year: u32, day: u32) -> PathBuf {
+        self.input_dir
+            .join(year.to_string())
+            .join(format!("d{:02}", day))
+            .join("input.txt")
+    }
+
+    /// the session cookie from `session_file`, without the newline most
+    /// editors leave on the end
+    pub fn session(&self) -> Result<String, Error> {
+        let text = fs::read_to_string(&self.session_file)
+            .map_err(|err| Error::Io(self.session_file.clone(), err))?;
+        Ok(text.trim().to_string())
+    }
+
+    /// the settings for a day, if there are any
+    pub fn day(&self, year: u32, day: u32) -> Option<&DayConfig> {
+        self.days
+            .get(&year.to_string())?
+            .get(&format!("d{:02}", day))
+    }
+
+    /// the settings handed to a day's solvers: its table from aoc.toml, with
+    /// any `--set key=value` overrides on top. values are read as toml,
+    /// falling back to a plain string, and spaces around either side of the
+    /// `=` don't count
+    pub fn options(
+        &self,
+        year: u32,
+        day: u32,
+        overrides: &[String],
+    ) -> Result<Options, OptionError> {
+        let mut options = self
+            .day(year, day)
+            .map(|settings| settings.options.clone())
+            .unwrap_or_default();
+
+        for item in overrides {
+            let Some((key, value)) = item.split_once('=') else {
+                return Err(OptionError::new(format!(
+                    "`--set {}` should look like key=value",
+                    item
+                )));
+            };
+            let (key, value) = (key.trim(), value.trim());
+
+            let value = match format!("value = {}", value).parse::<toml::Table>() {
+                Ok(mut table) => table.remove("value").unwrap(),
+                Err(_) => toml::Value::String(value.to_string()),
+            };
+            options.insert(key.to_string(), value);
+        }
+
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_input_path() {
+        // the inputs live next to each day's Cargo.toml, where `input_path`
+        // looks for them when `input_dir` isn't set
+        let path = Config::default().input_path(2023, 1);
+        assert_eq!(path, crate::day_dir(2023, 1).join("input.txt"));
+        assert!(path.with_file_name("Cargo.toml").exists());
+
+        // and d01's input is checked in there, one way or the other
+        assert!(path.exists() || crate::inputs::encrypted_path(&path).exists());
+    }
+
+    /// writes each layer to its own file, lowest precedence first
+    fn layers(name: &str, layers: &[&str]) -> Vec<PathBuf> {
+        let dir = std::env::temp_dir().join(format!("aoc-config-{}-{}", std::process::id(), name));
+        fs::create_dir_all(&dir).unwrap();
+        layers
+            .iter()
+            .enumerate()
+            .map(|(i, text)| {
+                let path = dir.join(format!("{}.toml", i));
+                fs::write(&path, text).unwrap();
+                path
+            })
+            .collect()
+    }
+
+    const REPO: &str = "
+output = \"json\"
+bench_iterations = 50
+
+[days.2023.d02]
+bag = { red = 12, green = 13, blue = 14 }
+strict = true
+";
+
+    const USER: &str = "
+bench_iterations = 5
+
+[days.2023.d02]
+strict = false
+";
+
+    #[test]
+    fn defaults_without_any_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-config-{}-none", std::process::id()));
+        let config = Config::load_from(&[dir.join("aoc.toml")]).unwrap();
+
+        assert_eq!(config.output, Output::Text);
+        assert_eq!(config.bench_iterations, 10);
+        assert_eq!(config.time_budget_ms, None);
+        assert_eq!(config.input_dir, root());
+        assert!(config.days.is_empty());
+    }
+
+    #[test]
+    fn user_file_over_repo_file() {
+        let config = Config::load_from(&layers("layered", &[REPO, USER])).unwrap();
+
+        // the user file wins where both set something...
+        assert_eq!(config.bench_iterations, 5);
+        // ...the repo file still counts where it doesn't...
+        assert_eq!(config.output, Output::Json);
+
+        // ...and day tables are merged key by key, not replaced wholesale
+        let d02 = &config.day(2023, 2).unwrap().options;
+        assert_eq!(d02["strict"], toml::Value::Boolean(false));
+        assert_eq!(d02["bag"]["red"], toml::Value::Integer(12));
+    }
+
+    #[test]
+    fn set_over_both_files() {
+        let config = Config::load_from(&layers("set", &[REPO, USER])).unwrap();
+        let overrides = [
+            "strict=true".to_string(),
+            "bag.red = 20".to_string(),
+            " word = seven".to_string(),
+        ];
+        let options = config.options(2023, 2, &overrides).unwrap();
+
+        assert_eq!(options["strict"], toml::Value::Boolean(true));
+        // the key is taken as is, dots and all, so this doesn't reach into `bag`
+        assert_eq!(options["bag.red"], toml::Value::Integer(20));
+        assert_eq!(options["bag"]["red"], toml::Value::Integer(12));
+        // anything that isn't toml is a string, without the spaces around it
+        assert_eq!(options["word"], toml::Value::String("seven".to_string()));
+
+        // days without a table start from nothing
+        let options = config.options(2023, 3, &overrides[..1]).unwrap();
+        assert_eq!(options.len(), 1);
+    }
+
+    #[test]
+    fn session_from_its_file() {
+        let paths = layers("session", &[REPO]);
+        let dir = paths[0].parent().unwrap();
+        fs::write(dir.join("session"), "53616c7465645f5f\n").unwrap();
+
+        // relative to the root of the repo, so absolute here
+        let user = format!("session_file = {:?}\n", dir.join("session"));
+        fs::write(&paths[0], user).unwrap();
+        let config = Config::load_from(&paths).unwrap();
+        assert_eq!(config.session().unwrap(), "53616c7465645f5f");
+
+        // and the file not being there says which file it was looking for
+        let missing = Config {
+            session_file: dir.join("missing"),
+            ..Config::default()
+        };
+        let err = missing.session().unwrap_err().to_string();
+        assert!(err.starts_with(&format!(
+            "couldn't read {}: ",
+            dir.join("missing").display()
+        )));
+    }
+
+    #[test]
+    fn set_without_equals() {
+        let config = Config::default();
+        let err = config
+            .options(2023, 2, &["strict".to_string()])
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "`--set strict` should look like key=value");
+    }
+
+    #[test]
+    fn mistakes_blamed_on_their_file() {
+        let paths = layers("unknown", &[REPO, "bench_iteration = 5\n"]);
+        let err = Config::load_from(&paths).unwrap_err();
+
+        match err {
+            Error::Parse(path, err) => {
+                assert_eq!(path, paths[1]);
+                assert!(err.to_string().contains("bench_iteration"), "{}", err);
+            }
+            err => panic!("expected a parse error, got {}", err),
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! a day's parsed input as a tidy table, for spreadsheets and dataframes, and
+//! back again. only d02 has one so far: a row per colour drawn in each round,
+//!
+//! ```text
+//! game,round,colour,count
+//! 1,1,blue,3
+//! 1,1,red,4
+//! ```
+//!
+//! as csv with that header, or as json, an array of objects with the same
+//! fields. importing a table writes the input it came from, so exporting and
+//! importing again is a check on the parser as well.
+
+use std::fmt;
+
+use aoc2023_d02::rows::{self, Row};
+use clap::ValueEnum;
+use scan::ParseError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+impl Format {
+    /// the format a file's extension says it's in, if it says
+    pub fn from_extension(extension: &str) -> Option<Format> {
+        match extension.to_ascii_lowercase().as_str() {
+            "csv" => Some(Format::Csv),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// there's no table for this day
+    Unsupported(u32, u32),
+    /// the puzzle input doesn't parse
+    Input(ParseError),
+    /// a line of csv is malformed, or describes a game that can't be
+    Csv(ParseError),
+    Json(serde_json::Error),
+    /// a json row, counting from 1, describes a game that can't be
+    Row(usize, String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Unsupported(year, day) => {
+                write!(f, "{} day {} can't be exported or imported", year, day)
+            }
+            Error::Input(err) => write!(f, "input {}", err),
+            Error::Csv(err) => write!(f, "csv {}", err),
+            Error::Json(err) => write!(f, "json: {}", err),
+            Error::Row(row, reason) => write!(f, "json row {}: {}", row, reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// one row as it's written out, owning its colour so it can be read back in
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Record {
+    game: u32,
+    round: usize,
+    colour: String,
+    count: u32,
+}
+
+const HEADER: [&str; 4] = ["game", "round", "colour", "count"];
+
+/// the table for `year`'s `day`, read from its puzzle input
+pub fn export(year: u32, day: u32, binding: &str, format: Format) -> Result<String, Error> {
+    if (year, day) != (2023, 2) {
+        return Err(Error::Unsupported(year, day));
+    }
+
+    let games = aoc2023_d02::parse(binding).map_err(Error::Input)?;
+    let records = rows::rows(&games)
+        .iter()
+        .map(|row| Record {
+            game: row.game,
+            round: row.round,
+            colour: row.colour.to_string(),
+            count: row.count,
+        })
+        .collect::<Vec<Record>>();
+
+    Ok(match format {
+        Format::Csv => to_csv(&records),
+        Format::Json => serde_json::to_string_pretty(&records).map_err(Error::Json)? + "\n",
+    })
+}
+
+/// the puzzle input a table describes, as `export` writes it
+pub fn import(year: u32, day: u32, data: &str, format: Format) -> Result<String, Error> {
+    if (year, day) != (2023, 2) {
+        return Err(Error::Unsupported(year, day));
+    }
+
+    // where each record came from, for errors: a line for csv, a row for json
+    let (records, origins) = match format {
+        Format::Csv => from_csv(data)?,
+        Format::Json => {
+            let records = serde_json::from_str::<Vec<Record>>(data).map_err(Error::Json)?;
+            let origins = (0..records.len()).collect();
+            (records, origins)
+        }
+    };
+
+    let rows = records
+        .iter()
+        .map(|record| Row {
+            game: record.game,
+            round: record.round,
+            colour: &record.colour,
+            count: record.count,
+        })
+        .collect::<Vec<Row>>();
+    let games = rows::games(&rows).map_err(|err| {
+        let origin = origins[err.line - 1];
+        match format {
+            Format::Csv => Error::Csv(ParseError::new(origin, err.reason)),
+            Format::Json => Error::Row(origin + 1, err.reason),
+        }
+    })?;
+
+    Ok(games.iter().map(|game| format!("{}\n", game)).collect())
+}
+
+/// quoted only when it has to be, which for a colour means a `"` in it
+fn csv_field(field: &str) -> String {
+    if field.contains(|c| ",\"\r\n".contains(c)) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn to_csv(records: &[Record]) -> String {
+    let mut out = HEADER.join(",") + "\n";
+    for record in records {
+        out += &format!(
+            "{},{},{},{}\n",
+            record.game,
+            record.round,
+            csv_field(&record.colour),
+            record.count
+        );
+    }
+    out
+}
+
+/// splits a line of csv into its fields, undoing any quoting
+fn split_csv(idx: usize, line: &str) -> Result<Vec<String>, ParseError> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    loop {
+        let mut field = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => return Err(ParseError::new(idx, "unclosed `\"`")),
+                }
+            }
+        }
+        while let Some(&c) = chars.peek() {
+            if c == ',' {
+                break;
+            }
+            if c == '"' {
+                return Err(ParseError::new(idx, "stray `\"` in a field"));
+            }
+            field.push(c);
+            chars.next();
+        }
+        fields.push(field);
+
+        if chars.next().is_none() {
+            return Ok(fields);
+        }
+    }
+}
+
+fn number<T: std::str::FromStr>(idx: usize, field: &str, name: &str) -> Result<T, ParseError> {
+    field
+        .trim()
+        .parse()
+        .map_err(|_| ParseError::new(idx, format!("expected a {}, found `{}`", name, field)))
+}
+
+/// every record in the csv, and the line each is on. blank lines are fine,
+/// and so are spreadsheets' `\r\n`s
+fn from_csv(data: &str) -> Result<(Vec<Record>, Vec<usize>), Error> {
+    let mut lines = data
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    match lines.next() {
+        Some((idx, line)) => {
+            if split_csv(idx, line).map_err(Error::Csv)? != HEADER {
+                return Err(Error::Csv(ParseError::new(
+                    idx,
+                    format!("the header should be `{}`", HEADER.join(",")),
+                )));
+            }
+        }
+        None => {
+            return Err(Error::Csv(ParseError::new(0, "there's no header")));
+        }
+    }
+
+    let mut records = Vec::new();
+    let mut origins = Vec::new();
+    for (idx, line) in lines {
+        let fields = split_csv(idx, line).map_err(Error::Csv)?;
+        let [game, round, colour, count] = fields.as_slice() else {
+            return Err(Error::Csv(ParseError::new(
+                idx,
+                format!("expected 4 fields, found {}", fields.len()),
+            )));
+        };
+
+        records.push(Record {
+            game: number(idx, game, "game id").map_err(Error::Csv)?,
+            round: number(idx, round, "round").map_err(Error::Csv)?,
+            colour: colour.trim().to_string(),
+            count: number(idx, count, "count").map_err(Error::Csv)?,
+        });
+        origins.push(idx);
+    }
+
+    Ok((records, origins))
+}
@@ -0,0 +1,169 @@
+//! the C interface. everything in here is part of the ABI, so existing
+//! signatures and status codes shouldn't change; bump `AOC_ABI_VERSION` and
+//! add new functions instead.
+
+use std::ffi::c_char;
+use std::panic;
+use std::slice;
+
+use crate::SolveError;
+
+/// the header for everything in here, as cbindgen writes it at build time
+///
+/// cbindgen:ignore
+pub const HEADER: &str = include_str!(concat!(env!("OUT_DIR"), "/aoc.h"));
+
+/// bumped whenever the C interface changes in an incompatible way
+pub const AOC_ABI_VERSION: u32 = 1;
+
+/// the answer was written to `out_buf`
+pub const AOC_OK: i32 = 0;
+/// `input_ptr` or `out_buf` was null
+pub const AOC_ERR_NULL: i32 = -1;
+/// there's no solver for the requested year, day, and part
+pub const AOC_ERR_UNKNOWN: i32 = -2;
+/// the input wasn't valid utf-8
+pub const AOC_ERR_UTF8: i32 = -3;
+/// the solver panicked, most likely on malformed input
+pub const AOC_ERR_PANIC: i32 = -4;
+/// `out_buf` is too small to hold the answer and its trailing nul
+pub const AOC_ERR_BUFFER: i32 = -5;
+/// the input isn't in the shape the puzzle says it should be
+pub const AOC_ERR_INPUT: i32 = -6;
+/// there's no answer to give, like one too big for the solver's type
+pub const AOC_ERR_ANSWER: i32 = -7;
+
+/// returns the `AOC_ABI_VERSION` the library was built with
+#[no_mangle]
+pub extern "C" fn aoc_abi_version() -> u32 {
+    AOC_ABI_VERSION
+}
+
+/// solves `part` of `day` from 2023 for the `len` bytes of utf-8 input at
+/// `input_ptr`.
+///
+/// on success, the answer is written to `out_buf` as a nul-terminated string
+/// and `AOC_OK` is returned. otherwise one of the negative `AOC_ERR_*` codes
+/// is returned and `out_buf` is left untouched.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `len` readable bytes, and `out_buf` must point to
+/// `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input_ptr: *const u8,
+    len: usize,
+    out_buf: *mut u8,
+    out_len: usize,
+) -> i32 {
+    aoc_solve_year(2023, day, part, input_ptr, len, out_buf, out_len)
+}
+
+/// the same as `aoc_solve`, but for a puzzle from any year.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `len` readable bytes, and `out_buf` must point to
+/// `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve_year(
+    year: u32,
+    day: u32,
+    part: u32,
+    input_ptr: *const u8,
+    len: usize,
+    out_buf: *mut u8,
+    out_len: usize,
+) -> i32 {
+    if input_ptr.is_null() || out_buf.is_null() {
+        return AOC_ERR_NULL;
+    }
+
+    let input = slice::from_raw_parts(input_ptr, len);
+    let Ok(input) = std::str::from_utf8(input) else {
+        return AOC_ERR_UTF8;
+    };
+
+    // a panic can't be allowed to unwind across the ffi boundary
+    let answer = match panic::catch_unwind(|| crate::solve(year, day, part, input)) {
+        Ok(Some(Ok(answer))) => answer,
+        Ok(Some(Err(err))) => return code(&err),
+        Ok(None) => return AOC_ERR_UNKNOWN,
+        Err(_) => return AOC_ERR_PANIC,
+    };
+
+    if answer.len() + 1 > out_len {
+        return AOC_ERR_BUFFER;
+    }
+
+    let out = slice::from_raw_parts_mut(out_buf, out_len);
+    out[..answer.len()].copy_from_slice(answer.as_bytes());
+    out[answer.len()] = 0;
+
+    AOC_OK
+}
+
+/// the `AOC_ERR_*` code for why a solver gave up
+fn code(err: &SolveError) -> i32 {
+    match err {
+        SolveError::Parse(_) | SolveError::Stream(_) => AOC_ERR_INPUT,
+        // `aoc_solve` only uses the default settings, so one being wrong is
+        // no more the caller's fault than an answer that doesn't fit
+        SolveError::Option(_) | SolveError::Answer(_) => AOC_ERR_ANSWER,
+    }
+}
+
+/// bumped whenever the plugin interface changes in an incompatible way
+pub const AOC_PLUGIN_ABI_VERSION: u32 = 1;
+
+/// solves a part for the `len` bytes of utf-8 input at `input_ptr`, with the
+/// day's settings from aoc.toml as the `options_len` bytes of toml at
+/// `options_ptr`. follows the same rules as `aoc_solve`: write a
+/// nul-terminated answer to `out_buf` and return `AOC_OK`, or return one of
+/// the `AOC_ERR_*` codes. the runner retries with a bigger buffer on
+/// `AOC_ERR_BUFFER`.
+pub type AocPluginSolve = unsafe extern "C" fn(
+    input_ptr: *const u8,
+    len: usize,
+    options_ptr: *const u8,
+    options_len: usize,
+    out_buf: *mut u8,
+    out_len: usize,
+) -> i32;
+
+/// one part of one day that a plugin can solve. it's run as a variant called
+/// `name`, so pick names that don't clash with the day's own variants
+#[repr(C)]
+pub struct AocPluginSolver {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    /// nul-terminated utf-8
+    pub name: *const c_char,
+    pub solve: AocPluginSolve,
+}
+
+/// what a plugin registers with the runner.
+///
+/// a plugin is a shared library exporting
+///
+/// ```c
+/// const AocPlugin *aoc_plugin_register(uint32_t abi_version);
+/// ```
+///
+/// which is called once, with the runner's `AOC_PLUGIN_ABI_VERSION`, when the
+/// plugin is loaded. it should return null if it can't speak that version,
+/// and otherwise a pointer that, along with everything it points to, stays
+/// valid until the library is unloaded.
+#[repr(C)]
+pub struct AocPlugin {
+    /// the `AOC_PLUGIN_ABI_VERSION` the plugin was built against
+    pub abi_version: u32,
+    /// nul-terminated utf-8, shown next to its solvers in `aoc list`
+    pub name: *const c_char,
+    pub solvers: *const AocPluginSolver,
+    pub solver_count: usize,
+}
@@ -0,0 +1,110 @@
+//! what each fuzz target in `fuzz/` runs. it lives here rather than in the
+//! fuzz crate so that `tests/regressions.rs` can replay saved crashes through
+//! exactly the same code as part of the main workspace.
+
+use crate::{find, find_stream, Options};
+
+/// the days with a `-solve` fuzz target. the rest of the solvers trust their
+/// input once it's parsed: d05's part 2 brute forces every seed it's given and
+/// d08 walks until it finds a `Z`, so arbitrary bytes would only hang them
+pub const SOLVED: &[(u32, u32)] = &[
+    (2023, 1),
+    (2023, 2),
+    (2023, 3),
+    (2023, 4),
+    (2023, 7),
+    (2023, 9),
+];
+
+/// feeds `data` to everything `day` uses to read its input. parsers are free
+/// to reject it, they just can't panic or hang
+pub fn parse(year: u32, day: u32, data: &[u8]) {
+    // every day reads its input as a `&str`, so there's nothing to find in
+    // bytes that aren't utf-8
+    let Ok(binding) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    match (year, day) {
+        (2023, 1) => {
+            let _ = aoc2023_d01::parse(binding, false);
+            let _ = aoc2023_d01::parse(binding, true);
+        }
+        (2023, 2) => {
+            let _ = aoc2023_d02::parse(binding);
+        }
+        (2023, 3) => {
+            let _ = aoc2023_d03::part_numbers(binding);
+            let _ = aoc2023_d03::gears(binding);
+        }
+        (2023, 4) => {
+            let _ = aoc2023_d04::parse(binding);
+        }
+        (2023, 5) => {
+            let _ = aoc2023_d05::parse(binding);
+        }
+        (2023, 6) => {
+            let _ = aoc2023_d06::parse(binding);
+            let _ = aoc2023_d06::parse_joined(binding);
+        }
+        (2023, 7) => {
+            let _ = aoc2023_d07::part1_hands(binding);
+            let _ = aoc2023_d07::part2_hands(binding);
+        }
+        (2023, 8) => {
+            let _ = aoc2023_d08::parse(binding);
+        }
+        (2023, 9) => {
+            let _ = aoc2023_d09::parse(binding);
+        }
+        (2023, 10) => {
+            // the walk is where d10 finds out whether the loop makes sense
+            if let Ok(walk) = aoc2023_d10::walk(binding) {
+                aoc2023_d10::classify(&walk);
+            }
+        }
+        _ => panic!("no fuzz target for {} day {}", year, day),
+    }
+}
+
+/// solves both parts of `day` for `data` with the default settings. a day
+/// that streams its input has to give the same answer from the whole input
+/// whenever the stream accepts it, since the whole-input solvers panic on
+/// input they can't read. the rest hand back their errors, so they just can't
+/// panic. does nothing for days that aren't in [`SOLVED`]
+pub fn solve(year: u32, day: u32, data: &[u8]) {
+    if !SOLVED.contains(&(year, day)) {
+        return;
+    }
+    let Ok(binding) = std::str::from_utf8(data) else {
+        return;
+    };
+    let options = Options::new();
+    let whole = find(year, day).unwrap();
+
+    for part in [1, 2] {
+        let Some(stream) = find_stream(year, day) else {
+            let _ = whole.solver(part).unwrap()(binding, &options);
+            for variant in whole.variants.iter().filter(|variant| variant.part == part) {
+                let _ = (variant.solver)(binding, &options);
+            }
+            continue;
+        };
+
+        let mut reader = data;
+        let Ok(streamed) = stream.solver(part).unwrap()(&mut reader, &options) else {
+            continue;
+        };
+        match whole.solver(part).unwrap()(binding, &options) {
+            Ok(answer) => assert_eq!(
+                answer, streamed,
+                "part {} streams a different answer from the whole input",
+                part
+            ),
+            Err(err) => panic!(
+                "part {} streams {} but the whole input fails with: {}",
+                part, streamed, err
+            ),
+        }
+    }
+}
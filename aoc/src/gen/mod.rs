@@ -0,0 +1,35 @@
+//! synthetic puzzle inputs. every generator is a pure function of its seed
+//! and size, so the same `aoc gen` invocation always produces the same file.
+
+mod y2023;
+
+pub use rng::Rng;
+
+/// a day's input generator, taking the rng and a day-specific size
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// every generator, as `(year, day, generator, size of the official input)`.
+/// what "size" means depends on the day; see each generator's docs
+static GENERATORS: &[(u32, u32, Generator, usize)] = &[
+    (2023, 1, y2023::d01, 1000),
+    (2023, 2, y2023::d02, 100),
+    (2023, 3, y2023::d03, 140),
+    (2023, 4, y2023::d04, 200),
+    (2023, 5, y2023::d05, 30),
+    (2023, 6, y2023::d06, 4),
+    (2023, 7, y2023::d07, 1000),
+    (2023, 8, y2023::d08, 750),
+    (2023, 9, y2023::d09, 200),
+    (2023, 10, y2023::d10, 140),
+];
+
+/// generates an input for `day` in `year`, or returns `None` if there's no
+/// generator for it. `size` defaults to roughly the size of an official input
+pub fn generate(year: u32, day: u32, seed: u64, size: Option<usize>) -> Option<String> {
+    let (_, _, generator, default_size) = GENERATORS
+        .iter()
+        .find(|(y, d, _, _)| *y == year && *d == day)?;
+
+    let mut rng = Rng::new(seed);
+    Some(generator(&mut rng, size.unwrap_or(*default_size)))
+}
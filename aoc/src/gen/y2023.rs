@@ -0,0 +1,459 @@
+use std::collections::HashSet;
+
+use super::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// `size` calibration lines. every line has at least one real digit so part 1
+/// always has something to find, mixed in with spelled out digits and noise
+pub fn d01(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..size {
+        let tokens = rng.range(1, 8);
+        let digit_at = rng.range(0, tokens - 1);
+
+        for token in 0..tokens {
+            match rng.range(0, 2) {
+                _ if token == digit_at => out.push((b'0' + rng.range(1, 9) as u8) as char),
+                0 => out.push((b'0' + rng.range(1, 9) as u8) as char),
+                1 => out += *rng.pick(&WORDS),
+                _ => {
+                    for _ in 0..rng.range(1, 5) {
+                        out.push(*rng.pick(LETTERS) as char);
+                    }
+                }
+            }
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// `size` games of one to six rounds each
+pub fn d02(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+
+    for id in 1..=size {
+        let mut rounds = Vec::new();
+        for _ in 0..rng.range(1, 6) {
+            let mut colours = ["red", "green", "blue"];
+            rng.shuffle(&mut colours);
+
+            let draws = colours[..rng.range(1, 3) as usize]
+                .iter()
+                .map(|colour| format!("{} {}", rng.range(1, 20), colour))
+                .collect::<Vec<String>>();
+            rounds.push(draws.join(", "));
+        }
+        out += &format!("Game {}: {}\n", id, rounds.join("; "));
+    }
+
+    out
+}
+
+/// a `size` by `size` schematic, with about as many numbers and symbols per
+/// tile as the official one
+pub fn d03(rng: &mut Rng, size: usize) -> String {
+    let symbols = ['*', '*', '*', '#', '+', '$', '@', '%', '&', '=', '-', '/'];
+    let mut out = String::new();
+
+    for _ in 0..size {
+        let mut col = 0;
+        while col < size {
+            let roll = rng.range(0, 99);
+            if roll < 8 {
+                let len = (rng.range(1, 3) as usize).min(size - col);
+                out.push((b'0' + rng.range(1, 9) as u8) as char);
+                for _ in 1..len {
+                    out.push((b'0' + rng.range(0, 9) as u8) as char);
+                }
+                col += len;
+
+                // numbers can't run straight into each other
+                if col < size {
+                    if rng.chance(0.2) {
+                        out.push(*rng.pick(&symbols));
+                    } else {
+                        out.push('.');
+                    }
+                    col += 1;
+                }
+            } else if roll < 12 {
+                out.push(*rng.pick(&symbols));
+                col += 1;
+            } else {
+                out.push('.');
+                col += 1;
+            }
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// `size` scratchcards with ten winning numbers and twenty five chosen ones.
+/// winners are rare enough that a card hands out fewer than one copy on
+/// average, so the number of cards grows in line with the table rather than
+/// exponentially, however long it gets. cards near the end can still win
+/// copies of cards past it, which don't count
+pub fn d04(rng: &mut Rng, size: usize) -> String {
+    let width = size.to_string().len();
+    let mut out = String::new();
+
+    for idx in 0..size {
+        let matches = if rng.chance(0.15) {
+            rng.range(1, 10) as usize
+        } else {
+            0
+        };
+
+        let mut pool = (1..=99).collect::<Vec<u32>>();
+        rng.shuffle(&mut pool);
+        let winners = &pool[..10];
+        let mut chosen = winners[..matches].to_vec();
+        chosen.extend_from_slice(&pool[10..10 + 25 - matches]);
+        rng.shuffle(&mut chosen);
+
+        let winners = winners
+            .iter()
+            .map(|x| format!("{:>2}", x))
+            .collect::<Vec<String>>();
+        let chosen = chosen
+            .iter()
+            .map(|x| format!("{:>2}", x))
+            .collect::<Vec<String>>();
+        out += &format!(
+            "Card {:>width$}: {} | {}\n",
+            idx + 1,
+            winners.join(" "),
+            chosen.join(" "),
+            width = width
+        );
+    }
+
+    out
+}
+
+/// an almanac with `size` ranges in each of the seven maps. seed ranges are
+/// kept short so that brute forcing part 2 still finishes
+pub fn d05(rng: &mut Rng, size: usize) -> String {
+    const LIMIT: u64 = 4_000_000_000;
+    let names = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+
+    let seeds = (0..10)
+        .map(|_| format!("{} {}", rng.range(0, LIMIT), rng.range(1, 1000)))
+        .collect::<Vec<String>>();
+    let mut out = format!("seeds: {}\n", seeds.join(" "));
+
+    for name in names {
+        // cut the number line up so that no two source ranges overlap
+        let mut cuts = (0..size.max(1))
+            .map(|_| rng.range(0, LIMIT))
+            .collect::<Vec<u64>>();
+        cuts.push(0);
+        cuts.push(LIMIT);
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let mut lines = Vec::new();
+        for window in cuts.windows(2) {
+            let (source, range) = (window[0], window[1] - window[0]);
+            if rng.chance(0.1) {
+                continue;
+            }
+            let dest = rng.range(0, LIMIT - range);
+            lines.push(format!("{} {} {}", dest, source, range));
+        }
+        rng.shuffle(&mut lines);
+
+        out += &format!("\n{} map:\n{}\n", name, lines.join("\n"));
+    }
+
+    out
+}
+
+/// up to four races, as in the official input. any more and part 2's joined
+/// up numbers stop fitting in an `f64` without losing precision, so `size` is
+/// capped at 4. the first race is picked so that the joined race is winnable
+pub fn d06(rng: &mut Rng, size: usize) -> String {
+    let mut times = Vec::new();
+    let mut best = Vec::new();
+
+    for idx in 0..size.clamp(1, 4) {
+        let (t, b) = if idx == 0 {
+            (rng.range(64, 99), rng.range(100, 999))
+        } else {
+            let t = rng.range(10, 99);
+            (t, rng.range(1, t * t / 4 - 1))
+        };
+        times.push(format!("{:>7}", t));
+        best.push(format!("{:>7}", b));
+    }
+
+    format!("Time:    {}\nDistance:{}\n", times.concat(), best.concat())
+}
+
+/// `size` hands of camel cards with bids up to 1000
+pub fn d07(rng: &mut Rng, size: usize) -> String {
+    let cards = b"23456789TJQKA";
+    let mut out = String::new();
+
+    for _ in 0..size {
+        for _ in 0..5 {
+            out.push(*rng.pick(cards) as char);
+        }
+        out += &format!(" {}\n", rng.range(1, 1000));
+    }
+
+    out
+}
+
+/// a network of roughly `size` nodes, up to about 20k. every `..A` node starts
+/// a chain that reaches its `..Z` node after `k` steps and then loops back
+/// around every `k` steps, whichever way the instructions say to turn; that's
+/// the property part 2's lcm relies on. `AAA` and `ZZZ` are one such chain
+pub fn d08(rng: &mut Rng, size: usize) -> String {
+    const ALNUM: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    const MIDDLE: &[u8] = b"0123456789BCDEFGHIJKLMNOPQRSTUVWXY";
+    let mut primes = [43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
+    rng.shuffle(&mut primes);
+
+    let ghosts = &primes[..6];
+    // each chain takes two nodes per step, and there are only 44064 labels to
+    // go around, so the network tops out at about 20k nodes
+    let scale = (size / (2 * ghosts.iter().sum::<usize>())).clamp(1, 20);
+
+    let mut used = HashSet::new();
+    let mut label = |rng: &mut Rng, last: &[u8]| loop {
+        let candidate = format!(
+            "{}{}{}",
+            *rng.pick(ALNUM) as char,
+            *rng.pick(ALNUM) as char,
+            *rng.pick(last) as char
+        );
+        if candidate != "AAA" && candidate != "ZZZ" && used.insert(candidate.clone()) {
+            return candidate;
+        }
+    };
+
+    let mut nodes = Vec::new();
+    for (idx, prime) in ghosts.iter().enumerate() {
+        let k = prime * scale;
+        let (start, end) = if idx == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            (label(rng, b"A"), label(rng, b"Z"))
+        };
+
+        // steps[i] holds the two nodes we could be on after i + 1 steps
+        let steps = (1..k)
+            .map(|_| (label(rng, MIDDLE), label(rng, MIDDLE)))
+            .collect::<Vec<(String, String)>>();
+
+        let mut link = |rng: &mut Rng, from: &String, to: &(String, String)| {
+            if rng.chance(0.5) {
+                nodes.push(format!("{} = ({}, {})", from, to.0, to.1));
+            } else {
+                nodes.push(format!("{} = ({}, {})", from, to.1, to.0));
+            }
+        };
+
+        link(rng, &start, &steps[0]);
+        link(rng, &end, &steps[0]);
+        for i in 0..steps.len() {
+            let next = match steps.get(i + 1) {
+                Some(next) => next.clone(),
+                None => (end.clone(), end.clone()),
+            };
+            link(rng, &steps[i].0, &next);
+            link(rng, &steps[i].1, &next);
+        }
+    }
+    rng.shuffle(&mut nodes);
+
+    let instructions = (0..rng.range(2, 300))
+        .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+        .collect::<String>();
+
+    format!("{}\n\n{}\n", instructions, nodes.join("\n"))
+}
+
+/// `size` sequences of 21 values, each taken from a polynomial of degree three
+/// or less with small coefficients
+pub fn d09(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+
+    for _ in 0..size {
+        let coeffs = (0..rng.range(1, 4))
+            .map(|_| rng.irange(-3, 3))
+            .collect::<Vec<i64>>();
+        let at = |x: i64| coeffs.iter().rev().fold(0, |acc, c| acc * x + c);
+
+        let values = (0..21).map(|x| at(x).to_string()).collect::<Vec<String>>();
+        out += &values.join(" ");
+        out.push('\n');
+    }
+
+    out
+}
+
+/// whether a cell can join the region without punching a hole in it or
+/// leaving two cells touching only at a corner, either of which would make the
+/// region's outline something other than a single loop
+fn can_grow(region: &[Vec<bool>], r: usize, c: usize) -> bool {
+    // clockwise from north
+    let around = [
+        (-1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (0, -1),
+        (-1, -1),
+    ]
+    .map(|(dr, dc)| region[(r as isize + dr) as usize][(c as isize + dc) as usize]);
+
+    let orthogonal = around.iter().step_by(2).any(|x| *x);
+    let runs = (0..8)
+        .filter(|i| around[*i] && !around[(i + 7) % 8])
+        .count();
+
+    orthogonal && runs == 1
+}
+
+/// a `size` by `size` field of pipes, or 7 by 7 if `size` is any smaller,
+/// since the loop needs room. the loop is the outline of a random blob grown
+/// on a grid at half the resolution, so tiles on the loop are never next to
+/// each other unless they're connected, and the rest of the field is filled
+/// with junk pipe
+pub fn d10(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(7);
+    // the half resolution grid covers an odd number of tiles, so an even
+    // `size` has a spare row and column of junk along the bottom and right
+    let coarse = (size - 1) / 2;
+
+    // grow the blob, keeping a ring of empty cells around the edge so the
+    // loop never touches the edge of the field
+    let mut region = vec![vec![false; coarse]; coarse];
+    let start = (
+        rng.range(1, coarse as u64 - 2) as usize,
+        rng.range(1, coarse as u64 - 2) as usize,
+    );
+    region[start.0][start.1] = true;
+
+    let target = (coarse - 2) * (coarse - 2) / 2;
+    let mut grown = 1;
+    let mut frontier = vec![
+        (start.0 - 1, start.1),
+        (start.0 + 1, start.1),
+        (start.0, start.1 - 1),
+        (start.0, start.1 + 1),
+    ];
+    while grown < target && !frontier.is_empty() {
+        let idx = rng.range(0, frontier.len() as u64 - 1) as usize;
+        let (r, c) = frontier.swap_remove(idx);
+
+        // a cell that can't join yet gets pushed again if a neighbour does
+        if r < 1 || r >= coarse - 1 || c < 1 || c >= coarse - 1 {
+            continue;
+        }
+        if region[r][c] || !can_grow(&region, r, c) {
+            continue;
+        }
+        region[r][c] = true;
+        grown += 1;
+
+        frontier.extend([(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)]);
+    }
+
+    // trace the outline. each cell (r, c) covers fine tiles 2r..=2r+2 and
+    // 2c..=2c+2, and its edges are shared with its neighbours
+    let mut links = vec![vec![[false; 4]; size]; size]; // N, E, S, W
+    let horizontal = |links: &mut Vec<Vec<[bool; 4]>>, row: usize, col: usize| {
+        for j in col..col + 2 {
+            links[row][j][1] = true;
+            links[row][j + 1][3] = true;
+        }
+    };
+    let vertical = |links: &mut Vec<Vec<[bool; 4]>>, row: usize, col: usize| {
+        for i in row..row + 2 {
+            links[i][col][2] = true;
+            links[i + 1][col][0] = true;
+        }
+    };
+    for r in 0..coarse {
+        for c in 0..coarse {
+            if !region[r][c] {
+                continue;
+            }
+            if !region[r - 1][c] {
+                horizontal(&mut links, 2 * r, 2 * c);
+            }
+            if !region[r + 1][c] {
+                horizontal(&mut links, 2 * r + 2, 2 * c);
+            }
+            if !region[r][c - 1] {
+                vertical(&mut links, 2 * r, 2 * c);
+            }
+            if !region[r][c + 1] {
+                vertical(&mut links, 2 * r, 2 * c + 2);
+            }
+        }
+    }
+
+    let junk = ['|', '-', 'L', 'J', '7', 'F'];
+    let mut grid = vec![vec!['.'; size]; size];
+    let mut on_loop = Vec::new();
+    for i in 0..size {
+        for j in 0..size {
+            grid[i][j] = match links[i][j] {
+                [true, false, true, false] => '|',
+                [false, true, false, true] => '-',
+                [true, true, false, false] => 'L',
+                [true, false, false, true] => 'J',
+                [false, false, true, true] => '7',
+                [false, true, true, false] => 'F',
+                _ if rng.chance(0.5) => *rng.pick(&junk),
+                _ => '.',
+            };
+            if links[i][j].iter().any(|x| *x) {
+                on_loop.push((i, j));
+            }
+        }
+    }
+
+    // clear out the junk around `S`, so nothing looks like it connects to it
+    // when it doesn't
+    let (i, j) = *rng.pick(&on_loop);
+    for (dir, (ni, nj)) in [(i - 1, j), (i, j + 1), (i + 1, j), (i, j - 1)]
+        .into_iter()
+        .enumerate()
+    {
+        if !links[i][j][dir] {
+            grid[ni][nj] = '.';
+        }
+    }
+    grid[i][j] = 'S';
+
+    let mut out = String::new();
+    for row in grid {
+        out.extend(row);
+        out.push('\n');
+    }
+    out
+}
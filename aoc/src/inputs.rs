@@ -0,0 +1,300 @@
+//! puzzle inputs, which may be stored encrypted so the repo can carry working
+//! inputs without publishing them. an encrypted input sits next to where the
+//! plain one would be, as `input.txt.enc`, and is decrypted on load with a key
+//! that never leaves the machines it's shared to.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+
+use crate::root;
+
+/// every encrypted input starts with this, followed by the nonce and then the
+/// ciphertext. bump the version if the layout ever changes
+pub const MAGIC: &[u8] = b"AOCENC01";
+const NONCE_LEN: usize = 24;
+
+/// set to a hex key to use it instead of the key file, e.g. in CI
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, io::Error),
+    /// there's no key in `AOC_INPUT_KEY` or at the given path
+    NoKey(PathBuf),
+    /// the key isn't 64 hex digits
+    BadKey,
+    /// the file is truncated, was tampered with, or was encrypted with a
+    /// different key
+    Decrypt(PathBuf),
+    Utf8(PathBuf),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(path, err) => write!(f, "couldn't read {}: {}", path.display(), err),
+            Error::NoKey(path) => write!(
+                f,
+                "no key to decrypt inputs with; set {} or put one in {}",
+                KEY_VAR,
+                path.display()
+            ),
+            Error::BadKey => write!(f, "the input key should be 64 hex digits"),
+            Error::Decrypt(path) => write!(
+                f,
+                "couldn't decrypt {}; is it encrypted with a different key?",
+                path.display()
+            ),
+            Error::Utf8(path) => write!(f, "{} isn't valid utf-8", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// where the input key lives when it isn't in `AOC_INPUT_KEY`. it's
+/// gitignored, so it has to be handed around some other way
+pub fn key_path() -> PathBuf {
+    root().join(".aoc-key")
+}
+
+/// where the encrypted copy of the input at `plain` is stored
+pub fn encrypted_path(plain: &Path) -> PathBuf {
+    let mut path = plain.as_os_str().to_os_string();
+    path.push(".enc");
+    PathBuf::from(path)
+}
+
+fn parse_key(hex: &str) -> Result<Key, Error> {
+    let hex = hex.trim();
+    if hex.len() != 64 {
+        return Err(Error::BadKey);
+    }
+
+    let mut key = Key::default();
+    for (idx, byte) in key.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(hex.get(2 * idx..2 * idx + 2).ok_or(Error::BadKey)?, 16)
+            .map_err(|_| Error::BadKey)?;
+    }
+
+    Ok(key)
+}
+
+fn to_hex(key: &Key) -> String {
+    key.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// reads the key from `AOC_INPUT_KEY`, or failing that from the key file
+pub fn load_key() -> Result<Key, Error> {
+    key_from(std::env::var(KEY_VAR).ok(), key_path())
+}
+
+/// the key in `var` if it's set, otherwise the one in the file at `path`
+fn key_from(var: Option<String>, path: PathBuf) -> Result<Key, Error> {
+    if let Some(hex) = var {
+        return parse_key(&hex);
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(hex) => parse_key(&hex),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Err(Error::NoKey(path)),
+        Err(err) => Err(Error::Io(path, err)),
+    }
+}
+
+/// makes a fresh key and writes it to the key file, returning it
+pub fn create_key() -> io::Result<Key> {
+    let key = XChaCha20Poly1305::generate_key(&mut OsRng);
+    let hex = to_hex(&key);
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    // nobody else on the machine needs to read it
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options
+        .open(key_path())?
+        .write_all((hex + "\n").as_bytes())?;
+
+    Ok(key)
+}
+
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+pub fn encrypt(key: &Key, plain: &[u8]) -> Vec<u8> {
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let sealed = XChaCha20Poly1305::new(key)
+        .encrypt(&nonce, plain)
+        .expect("inputs are nowhere near chacha's length limit");
+
+    let mut out = MAGIC.to_vec();
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&sealed);
+    out
+}
+
+/// decrypts the contents of an encrypted input, or returns `None` if it's
+/// malformed or the key is wrong
+pub fn decrypt(key: &Key, data: &[u8]) -> Option<Vec<u8>> {
+    let rest = data.strip_prefix(MAGIC)?;
+    if rest.len() < NONCE_LEN {
+        return None;
+    }
+    let (nonce, sealed) = rest.split_at(NONCE_LEN);
+
+    XChaCha20Poly1305::new(key)
+        .decrypt(XNonce::from_slice(nonce), sealed)
+        .ok()
+}
+
+/// reads an input file, decrypting it first if it's encrypted
+pub fn read(path: &Path) -> Result<String, Error> {
+    read_with(path, load_key)
+}
+
+/// `read`, with the key coming from `key` (only when it's needed)
+fn read_with(path: &Path, key: impl FnOnce() -> Result<Key, Error>) -> Result<String, Error> {
+    let data = fs::read(path).map_err(|err| Error::Io(path.to_path_buf(), err))?;
+
+    let data = if is_encrypted(&data) {
+        decrypt(&key()?, &data).ok_or_else(|| Error::Decrypt(path.to_path_buf()))?
+    } else {
+        data
+    };
+
+    String::from_utf8(data).map_err(|_| Error::Utf8(path.to_path_buf()))
+}
+
+/// reads the input at `plain`, falling back to its encrypted copy
+pub fn load(plain: &Path) -> Result<String, Error> {
+    let encrypted = encrypted_path(plain);
+
+    if !plain.exists() && encrypted.exists() {
+        read(&encrypted)
+    } else {
+        read(plain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAIN: &str = "1abc2\npqr3stu8vwx\n";
+
+    /// a scratch directory of its own for each test, since they run at once
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}-{}", std::process::id(), name));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn key() -> Key {
+        XChaCha20Poly1305::generate_key(&mut OsRng)
+    }
+
+    /// writes `data` to `name` in `dir`, returning the path
+    fn file(dir: &Path, name: &str, data: &[u8]) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, data).unwrap();
+        path
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = key();
+        let sealed = encrypt(&key, PLAIN.as_bytes());
+        assert!(is_encrypted(&sealed));
+        assert_eq!(decrypt(&key, &sealed).as_deref(), Some(PLAIN.as_bytes()));
+
+        let dir = scratch("round-trip");
+        let path = file(&dir, "input.txt.enc", &sealed);
+        let hex = to_hex(&key);
+        assert_eq!(
+            read_with(&path, || key_from(Some(hex), dir.join("missing"))).unwrap(),
+            PLAIN
+        );
+
+        // plain files are read as they are, and never need a key
+        let plain = file(&dir, "input.txt", PLAIN.as_bytes());
+        assert_eq!(
+            read_with(&plain, || panic!("no key needed")).unwrap(),
+            PLAIN
+        );
+    }
+
+    #[test]
+    fn wrong_key_from_file() {
+        let dir = scratch("wrong-key-file");
+        let path = file(&dir, "input.txt.enc", &encrypt(&key(), PLAIN.as_bytes()));
+        let key_file = file(&dir, ".aoc-key", (to_hex(&key()) + "\n").as_bytes());
+
+        let err = read_with(&path, || key_from(None, key_file)).unwrap_err();
+        assert!(
+            matches!(err, Error::Decrypt(ref at) if *at == path),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn wrong_key_from_var() {
+        let dir = scratch("wrong-key-var");
+        let right = key();
+        let path = file(&dir, "input.txt.enc", &encrypt(&right, PLAIN.as_bytes()));
+        // the variable wins over a key file, even one with the right key
+        let key_file = file(&dir, ".aoc-key", to_hex(&right).as_bytes());
+
+        let err = read_with(&path, || key_from(Some(to_hex(&key())), key_file)).unwrap_err();
+        assert!(matches!(err, Error::Decrypt(_)), "{}", err);
+
+        let err = read_with(&path, || key_from(Some("not hex".into()), dir.clone())).unwrap_err();
+        assert!(matches!(err, Error::BadKey), "{}", err);
+    }
+
+    #[test]
+    fn no_key() {
+        let dir = scratch("no-key");
+        let path = file(&dir, "input.txt.enc", &encrypt(&key(), PLAIN.as_bytes()));
+
+        let err = read_with(&path, || key_from(None, dir.join(".aoc-key"))).unwrap_err();
+        assert!(matches!(err, Error::NoKey(_)), "{}", err);
+    }
+
+    #[test]
+    fn bad_magic() {
+        let key = key();
+        let mut sealed = encrypt(&key, PLAIN.as_bytes());
+        sealed[MAGIC.len() - 1] ^= 1;
+        assert!(!is_encrypted(&sealed));
+        assert_eq!(decrypt(&key, &sealed), None);
+
+        // without the header it's taken for a plain input, which ciphertext
+        // won't pass for
+        let dir = scratch("bad-magic");
+        let path = file(&dir, "input.txt.enc", &sealed);
+        let err = read_with(&path, || Ok(key)).unwrap_err();
+        assert!(matches!(err, Error::Utf8(_)), "{}", err);
+    }
+
+    #[test]
+    fn truncated_nonce() {
+        let key = key();
+        let sealed = encrypt(&key, PLAIN.as_bytes());
+        let truncated = &sealed[..MAGIC.len() + NONCE_LEN / 2];
+        assert!(is_encrypted(truncated));
+        assert_eq!(decrypt(&key, truncated), None);
+
+        let dir = scratch("truncated");
+        let path = file(&dir, "input.txt.enc", truncated);
+        let err = read_with(&path, || Ok(key)).unwrap_err();
+        assert!(matches!(err, Error::Decrypt(_)), "{}", err);
+    }
+}
@@ -0,0 +1,627 @@
+use std::fmt;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+use scan::{ParseError, StreamError};
+
+pub mod config;
+pub mod export;
+pub mod ffi;
+pub mod fuzz;
+pub mod gen;
+pub mod inputs;
+pub mod plugins;
+
+/// a day's settings from `aoc.toml`, handed to each of its solvers. most
+/// days don't have any
+pub type Options = toml::Table;
+
+/// a single part's solver, taking the puzzle input and returning the answer,
+/// unless the day's settings don't make sense or the answer can't be given
+pub type Solver = fn(&str, &Options) -> Result<String, SolveError>;
+
+/// another way of solving one part of a day, picked by name
+pub struct Variant {
+    pub name: &'static str,
+    pub part: u32,
+    pub solver: Solver,
+}
+
+/// a day's solvers, keyed by the year and day of the puzzle
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub part1: Solver,
+    pub part2: Solver,
+    pub variants: &'static [Variant],
+}
+
+impl Day {
+    /// the regular solver for `part`
+    pub fn solver(&self, part: u32) -> Option<Solver> {
+        match part {
+            1 => Some(self.part1),
+            2 => Some(self.part2),
+            _ => None,
+        }
+    }
+
+    /// the solver for `part` from the variant called `name`, if it has one
+    pub fn variant(&self, part: u32, name: &str) -> Option<Solver> {
+        self.variants
+            .iter()
+            .find(|variant| variant.part == part && variant.name == name)
+            .map(|variant| variant.solver)
+    }
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident) => {
+        day!($year, $day, $krate, [])
+    };
+    ($year:literal, $day:literal, $krate:ident, [$($variant:expr),* $(,)?]) => {
+        Day {
+            year: $year,
+            day: $day,
+            part1: |input, _| Ok($krate::part1(input).to_string()),
+            part2: |input, _| Ok($krate::part2(input).to_string()),
+            variants: &[$($variant),*],
+        }
+    };
+}
+
+macro_rules! variant {
+    ($name:literal, $part:literal, $solver:path) => {
+        Variant {
+            name: $name,
+            part: $part,
+            solver: |input, _| Ok($solver(input).to_string()),
+        }
+    };
+}
+
+/// a day's setting that it can't make sense of, from aoc.toml or `--set`.
+/// the message says which setting and what's wrong with it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptionError(pub String);
+
+impl OptionError {
+    fn new(message: impl Into<String>) -> OptionError {
+        OptionError(message.into())
+    }
+}
+
+impl fmt::Display for OptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for OptionError {}
+
+/// why a solver stopped: a bad setting, input it couldn't read (from a
+/// string or a stream), or an answer that can't be given, like one too big
+/// for its type
+#[derive(Debug)]
+pub enum SolveError {
+    Option(OptionError),
+    Parse(ParseError),
+    Stream(StreamError),
+    Answer(String),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Option(err) => write!(f, "{}", err),
+            SolveError::Parse(err) => write!(f, "{}", err),
+            SolveError::Stream(err) => write!(f, "{}", err),
+            SolveError::Answer(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<OptionError> for SolveError {
+    fn from(err: OptionError) -> SolveError {
+        SolveError::Option(err)
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> SolveError {
+        SolveError::Parse(err)
+    }
+}
+
+impl From<StreamError> for SolveError {
+    fn from(err: StreamError) -> SolveError {
+        SolveError::Stream(err)
+    }
+}
+
+impl From<aoc2023_d03::Part2Error> for SolveError {
+    fn from(err: aoc2023_d03::Part2Error) -> SolveError {
+        match err {
+            aoc2023_d03::Part2Error::Parse(err) => SolveError::Parse(err),
+            aoc2023_d03::Part2Error::Overflow(err) => SolveError::Answer(err.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for SolveError {
+    fn from(err: std::io::Error) -> SolveError {
+        SolveError::Stream(err.into())
+    }
+}
+
+/// d02's bag, from `bag` in its settings: either a table like
+/// `{ red = 12, green = 13, blue = 14 }` or a string like
+/// `"12 red, 13 green, 14 blue"`. `bag_file` names a file holding the
+/// string form instead. the puzzle's own bag when neither is set
+fn bag(options: &Options) -> Result<aoc2023_d02::Bag, OptionError> {
+    use aoc2023_d02::Bag;
+
+    let bag = match (options.get("bag"), options.get("bag_file")) {
+        (Some(_), Some(_)) => {
+            return Err(OptionError::new(
+                "set one of `bag` and `bag_file`, not both",
+            ))
+        }
+        (Some(toml::Value::Table(limits)), None) => {
+            let limits = limits
+                .iter()
+                .map(|(colour, limit)| {
+                    let limit = limit.as_integer().and_then(|x| u32::try_from(x).ok());
+                    let limit = limit
+                        .ok_or_else(|| OptionError::new("bag counts should be whole numbers"))?;
+                    Ok((colour, limit))
+                })
+                .collect::<Result<Vec<(&String, u32)>, OptionError>>()?;
+            Bag::new(limits)
+        }
+        (Some(toml::Value::String(spec)), None) => Bag::parse(spec),
+        (Some(_), None) => {
+            return Err(OptionError::new(
+                "`bag` should be a table of colours or a string",
+            ))
+        }
+        (None, Some(path)) => {
+            let path = path
+                .as_str()
+                .ok_or_else(|| OptionError::new("`bag_file` should be a path"))?;
+            let spec = std::fs::read_to_string(path)
+                .map_err(|err| OptionError(format!("couldn't read {}: {}", path, err)))?;
+            return Bag::parse(&spec).map_err(|err| OptionError(format!("{}: {}", path, err)));
+        }
+        (None, None) => return Ok(Bag::default()),
+    };
+
+    bag.map_err(|err| OptionError(format!("bad bag: {}", err)))
+}
+
+/// d02's rules for part 1: the bag's limits, plus anything in `constraints`
+/// in its settings, as a string of rules like `"round <= 20, game <= 100"`
+/// or a list of them
+fn constraints(
+    options: &Options,
+    bag: &aoc2023_d02::Bag,
+) -> Result<aoc2023_d02::Constraints, OptionError> {
+    use aoc2023_d02::Constraints;
+
+    let mut constraints = Constraints::from_bag(bag);
+    let specs = match options.get("constraints") {
+        None => Vec::new(),
+        Some(toml::Value::String(spec)) => vec![spec.as_str()],
+        Some(toml::Value::Array(specs)) => specs
+            .iter()
+            .map(|spec| {
+                spec.as_str()
+                    .ok_or_else(|| OptionError::new("`constraints` should be strings"))
+            })
+            .collect::<Result<Vec<&str>, OptionError>>()?,
+        Some(_) => {
+            return Err(OptionError::new(
+                "`constraints` should be a string of rules or a list of them",
+            ))
+        }
+    };
+
+    for spec in specs {
+        let extra = Constraints::parse(spec, bag)
+            .map_err(|err| OptionError(format!("bad constraint `{}`: {}", spec, err.reason)))?;
+        constraints.extend(extra);
+    }
+    Ok(constraints)
+}
+
+/// a true or false setting, false when it isn't set
+fn flag(options: &Options, key: &str) -> Result<bool, OptionError> {
+    match options.get(key) {
+        Some(value) => value
+            .as_bool()
+            .ok_or_else(|| OptionError(format!("`{}` should be true or false", key))),
+        None => Ok(false),
+    }
+}
+
+/// whether d02 should reject colours the bag doesn't have, from
+/// `strict = true` in its settings
+fn strict(options: &Options) -> Result<bool, OptionError> {
+    flag(options, "strict")
+}
+
+/// d03's rules, from `symbols` in its settings (a character class like
+/// `"[*#]"`), `adjacency` (`4`, `8` or `"chebyshev:<radius>"`) and `gear`.
+/// anything but a digit or `.` is a symbol, touching in 8 directions, when
+/// unset
+fn rules(options: &Options) -> Result<aoc2023_d03::Rules, OptionError> {
+    use aoc2023_d03::{Adjacency, Class, Rules};
+
+    let symbols = match options.get("symbols") {
+        Some(spec) => {
+            let spec = spec
+                .as_str()
+                .ok_or_else(|| OptionError::new("`symbols` should be a character class"))?;
+            Class::parse(spec).map_err(|err| OptionError(err.to_string()))?
+        }
+        None => Class::default(),
+    };
+    let adjacency = match options.get("adjacency") {
+        Some(toml::Value::Integer(n)) => Adjacency::parse(&n.to_string()),
+        Some(toml::Value::String(spec)) => Adjacency::parse(spec),
+        Some(_) => {
+            return Err(OptionError::new(
+                "`adjacency` should be 4, 8 or \"chebyshev:<radius>\"",
+            ))
+        }
+        None => Ok(Adjacency::default()),
+    };
+    let adjacency = adjacency.map_err(|err| OptionError(err.to_string()))?;
+
+    Ok(Rules {
+        symbols,
+        adjacency,
+        gears: gear_rule(options)?,
+    })
+}
+
+/// d03's idea of a gear, from a `gear` table in its settings like
+/// `{ symbols = "[*#]", count = "2+", reduce = "sum" }`. anything left out
+/// is the puzzle's: a `*` touching exactly 2 numbers, worth their product
+fn gear_rule(options: &Options) -> Result<aoc2023_d03::GearRule, OptionError> {
+    use aoc2023_d03::{Class, Count, GearRule, Reduce};
+
+    let mut rule = GearRule::default();
+    let Some(gear) = options.get("gear") else {
+        return Ok(rule);
+    };
+    let gear = gear.as_table().ok_or_else(|| {
+        OptionError::new("`gear` should be a table of `symbols`, `count` and `reduce`")
+    })?;
+
+    for (key, value) in gear {
+        let parsed = match (key.as_str(), value) {
+            ("symbols", toml::Value::String(spec)) => Class::parse(spec).map(|symbols| {
+                rule.symbols = symbols;
+            }),
+            ("count", toml::Value::Integer(k)) => Count::parse(&k.to_string()).map(|count| {
+                rule.count = count;
+            }),
+            ("count", toml::Value::String(spec)) => Count::parse(spec).map(|count| {
+                rule.count = count;
+            }),
+            ("reduce", toml::Value::String(spec)) => Reduce::parse(spec).map(|reduce| {
+                rule.reduce = reduce;
+            }),
+            ("symbols" | "count" | "reduce", _) => {
+                return Err(OptionError(format!("`gear.{}` should be a string", key)))
+            }
+            _ => {
+                return Err(OptionError(format!(
+                    "unknown key `gear.{}`; expected `symbols`, `count` or `reduce`",
+                    key
+                )))
+            }
+        };
+        parsed.map_err(|err| OptionError(err.to_string()))?;
+    }
+
+    Ok(rule)
+}
+
+/// d01's digit words, from `vocabulary` in its settings: the name of one of
+/// the presets (`"english"` or `"roman"`), a table of words like
+/// `{ zero = 0, one = 1 }`, or a list of either to combine. english when it
+/// isn't set
+fn vocabulary(options: &Options) -> Result<aoc2023_d01::vocabulary::Vocabulary, OptionError> {
+    use aoc2023_d01::vocabulary::Vocabulary;
+
+    fn add(vocabulary: &mut Option<Vocabulary>, value: &toml::Value) -> Result<(), OptionError> {
+        let invalid = |err: aoc2023_d01::vocabulary::Error| OptionError(err.to_string());
+        let words =
+            match value {
+                toml::Value::String(name) => Vocabulary::preset(name).map_err(invalid)?,
+                toml::Value::Table(words) => {
+                    let words = words
+                        .iter()
+                        .map(|(word, value)| {
+                            let value = value.as_integer().and_then(|x| u32::try_from(x).ok());
+                            let value = value.ok_or_else(|| {
+                                OptionError::new("vocabulary values should be whole numbers")
+                            })?;
+                            Ok((word, value))
+                        })
+                        .collect::<Result<Vec<(&String, u32)>, OptionError>>()?;
+                    Vocabulary::new(words).map_err(invalid)?
+                }
+                toml::Value::Array(values) => {
+                    for value in values {
+                        add(vocabulary, value)?;
+                    }
+                    return Ok(());
+                }
+                _ => return Err(OptionError::new(
+                    "`vocabulary` should be a preset's name, a table of words, or a list of them",
+                )),
+            };
+
+        match vocabulary {
+            Some(vocabulary) => vocabulary.extend(words.tokens()).map_err(invalid)?,
+            None => *vocabulary = Some(words),
+        }
+        Ok(())
+    }
+
+    let mut vocabulary = None;
+    if let Some(value) = options.get("vocabulary") {
+        add(&mut vocabulary, value)?;
+    }
+    Ok(vocabulary.unwrap_or_default())
+}
+
+/// whether d01 should skip lines it can't read instead of failing on them,
+/// from `lenient = true` in its settings
+fn lenient(options: &Options) -> Result<bool, OptionError> {
+    flag(options, "lenient")
+}
+
+/// d01's answer in lenient mode, with any lines it skipped listed on stderr
+fn report(report: aoc2023_d01::lenient::Report) -> String {
+    if !report.skipped.is_empty() {
+        eprint!("{}", report);
+    }
+    report.sum.to_string()
+}
+
+/// every day we have a solution for. new days need an entry here (and a
+/// dependency in aoc/Cargo.toml) before the runner can see them
+pub static DAYS: &[Day] = &[
+    Day {
+        year: 2023,
+        day: 1,
+        part1: |input, options| match lenient(options)? {
+            true => Ok(report(
+                aoc2023_d01::lenient::part1(input.as_bytes()).unwrap(),
+            )),
+            false => Ok(aoc2023_d01::part1(input).to_string()),
+        },
+        part2: |input, options| {
+            let vocabulary = vocabulary(options)?;
+            match lenient(options)? {
+                true => Ok(report(
+                    aoc2023_d01::lenient::part2(input.as_bytes(), &vocabulary).unwrap(),
+                )),
+                false => Ok(aoc2023_d01::part2_with(input, &vocabulary).to_string()),
+            }
+        },
+        variants: &[variant!("find", 2, aoc2023_d01::part2_find)],
+    },
+    Day {
+        year: 2023,
+        day: 2,
+        part1: |input, options| {
+            let bag = bag(options)?;
+            let strict = strict(options)?.then_some(&bag);
+            let constraints = constraints(options, &bag)?;
+            Ok(aoc2023_d02::part1_constrained(input, &constraints, strict)?.to_string())
+        },
+        part2: |input, options| {
+            Ok(aoc2023_d02::part2_with(input, &bag(options)?, strict(options)?)?.to_string())
+        },
+        variants: &[],
+    },
+    Day {
+        year: 2023,
+        day: 3,
+        part1: |input, options| Ok(aoc2023_d03::part1_with(input, &rules(options)?)?.to_string()),
+        part2: |input, options| Ok(aoc2023_d03::part2_with(input, &rules(options)?)?.to_string()),
+        variants: &[
+            Variant {
+                name: "rows",
+                part: 1,
+                solver: |input, _| Ok(aoc2023_d03::rows::part1(input)?.to_string()),
+            },
+            Variant {
+                name: "rows",
+                part: 2,
+                solver: |input, _| Ok(aoc2023_d03::rows::part2(input)?.to_string()),
+            },
+        ],
+    },
+    day!(2023, 4, aoc2023_d04),
+    day!(
+        2023,
+        5,
+        aoc2023_d05,
+        [variant!("dumb", 1, aoc2023_d05::d01_dumb::part1)]
+    ),
+    day!(2023, 6, aoc2023_d06),
+    day!(2023, 7, aoc2023_d07),
+    day!(
+        2023,
+        8,
+        aoc2023_d08,
+        [variant!("regex", 1, aoc2023_d08::p01_regex::part1)]
+    ),
+    day!(2023, 9, aoc2023_d09),
+    day!(2023, 10, aoc2023_d10),
+];
+
+/// a single part's solver that reads its input a line at a time
+pub type StreamSolver = fn(&mut dyn BufRead, &Options) -> Result<String, SolveError>;
+
+/// a day that can be solved without reading its whole input into memory
+pub struct Stream {
+    pub year: u32,
+    pub day: u32,
+    pub part1: StreamSolver,
+    pub part2: StreamSolver,
+}
+
+impl Stream {
+    pub fn solver(&self, part: u32) -> Option<StreamSolver> {
+        match part {
+            1 => Some(self.part1),
+            2 => Some(self.part2),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! stream {
+    ($year:literal, $day:literal, $krate:ident) => {
+        Stream {
+            year: $year,
+            day: $day,
+            part1: |reader, _| Ok($krate::part1_stream(reader)?.to_string()),
+            part2: |reader, _| Ok($krate::part2_stream(reader)?.to_string()),
+        }
+    };
+}
+
+/// the days that can stream their input, for stress inputs too big to fit in
+/// memory. only days that treat each line on its own belong here
+pub static STREAMS: &[Stream] = &[
+    Stream {
+        year: 2023,
+        day: 1,
+        part1: |reader, options| match lenient(options)? {
+            true => Ok(report(aoc2023_d01::lenient::part1(reader)?)),
+            false => Ok(aoc2023_d01::part1_stream(reader)?.to_string()),
+        },
+        part2: |reader, options| {
+            let vocabulary = vocabulary(options)?;
+            match lenient(options)? {
+                true => Ok(report(aoc2023_d01::lenient::part2(reader, &vocabulary)?)),
+                false => Ok(aoc2023_d01::part2_stream_with(reader, &vocabulary)?.to_string()),
+            }
+        },
+    },
+    Stream {
+        year: 2023,
+        day: 2,
+        part1: |reader, options| {
+            let bag = bag(options)?;
+            let strict = strict(options)?.then_some(&bag);
+            let constraints = constraints(options, &bag)?;
+            Ok(aoc2023_d02::part1_stream_constrained(reader, &constraints, strict)?.to_string())
+        },
+        part2: |reader, options| {
+            let (bag, strict) = (bag(options)?, strict(options)?);
+            Ok(aoc2023_d02::part2_stream(reader, &bag, strict)?.to_string())
+        },
+    },
+    stream!(2023, 4, aoc2023_d04),
+    stream!(2023, 7, aoc2023_d07),
+    stream!(2023, 9, aoc2023_d09),
+];
+
+pub fn find_stream(year: u32, day: u32) -> Option<&'static Stream> {
+    STREAMS
+        .iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
+
+/// shows how a day reads each line of its input, rather than just the
+/// answer. takes the input, the day's settings, and whether to use colour
+pub type Annotator = fn(&str, &Options, bool) -> Result<String, SolveError>;
+
+/// a day that can annotate its input, for `aoc run --annotate`
+pub struct Annotate {
+    pub year: u32,
+    pub day: u32,
+    pub annotate: Annotator,
+}
+
+pub static ANNOTATE: &[Annotate] = &[
+    Annotate {
+        year: 2023,
+        day: 1,
+        annotate: |input, options, colour| {
+            Ok(aoc2023_d01::annotate::annotate(
+                input,
+                &vocabulary(options)?,
+                colour,
+            ))
+        },
+    },
+    Annotate {
+        year: 2023,
+        day: 2,
+        annotate: |input, options, _| {
+            let bag = bag(options)?;
+            let strict = strict(options)?.then_some(&bag);
+            Ok(aoc2023_d02::report(
+                input,
+                &constraints(options, &bag)?,
+                strict,
+            )?)
+        },
+    },
+    Annotate {
+        year: 2023,
+        day: 3,
+        annotate: |input, options, _| Ok(aoc2023_d03::report(input, &rules(options)?)?),
+    },
+];
+
+pub fn find_annotate(year: u32, day: u32) -> Option<Annotator> {
+    ANNOTATE
+        .iter()
+        .find(|entry| entry.year == year && entry.day == day)
+        .map(|entry| entry.annotate)
+}
+
+/// the year used when one isn't asked for, i.e. the latest one we have
+pub fn default_year() -> u32 {
+    DAYS.iter().map(|day| day.year).max().unwrap_or(2023)
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
+
+/// solves `part` of `day` in `year` for the given puzzle input with the
+/// default settings, or returns `None` if there's no solver for that year,
+/// day, and part
+pub fn solve(year: u32, day: u32, part: u32, input: &str) -> Option<Result<String, SolveError>> {
+    let solver = find(year, day)?.solver(part)?;
+
+    Some(solver(input, &Options::new()))
+}
+
+/// the root of the repo, where each year's days live in `<year>/dNN`
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// the directory holding a given day's crate
+pub fn day_dir(year: u32, day: u32) -> PathBuf {
+    root().join(year.to_string()).join(format!("d{:02}", day))
+}
@@ -0,0 +1,794 @@
+use std::collections::BTreeSet;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+use aoc::config::{Config, Output};
+use aoc::export::{self, Format};
+use aoc::plugins::{self, Plugin, PluginSolver};
+use aoc::{inputs, Options, SolveError, Solver, StreamSolver};
+use clap::{Args, Parser, Subcommand};
+use serde::Serialize;
+
+mod scaffold;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "runs advent of code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// what to solve, shared by `run` and `bench`
+#[derive(Args)]
+struct Target {
+    /// defaults to the latest year we have solutions for
+    #[arg(long)]
+    year: Option<u32>,
+    #[arg(long)]
+    day: u32,
+    /// runs both parts when left out
+    #[arg(long)]
+    part: Option<u32>,
+    /// defaults to <input_dir>/<year>/dNN/input.txt
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// solve with a named variant instead, for the parts it covers. overrides
+    /// the day's `variant` in aoc.toml
+    #[arg(long)]
+    variant: Option<String>,
+    /// read the input a line at a time instead of all at once, for inputs too
+    /// big to fit in memory. only some days can
+    #[arg(long)]
+    stream: bool,
+    /// override one of the day's settings from aoc.toml, as `key=value` where
+    /// the value is toml (a bare word is taken as a string). can be repeated
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    set: Vec<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// solve a day's puzzle
+    Run {
+        #[command(flatten)]
+        target: Target,
+        /// instead of solving, show how each line of the input is read. only
+        /// some days can
+        #[arg(long)]
+        annotate: bool,
+    },
+    /// time a day's solvers over several runs
+    Bench {
+        #[command(flatten)]
+        target: Target,
+        /// defaults to `bench_iterations` in aoc.toml
+        #[arg(long)]
+        iterations: Option<usize>,
+    },
+    /// list every day the runner knows about
+    List {
+        #[arg(long)]
+        year: Option<u32>,
+    },
+    /// generate a synthetic puzzle input
+    Gen {
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// defaults to roughly the size of the official input
+        #[arg(long)]
+        size: Option<usize>,
+        /// prints to stdout when left out
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// write a day's parsed input as a table, one row per colour drawn in
+    /// each round. only some days can
+    Export {
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+        /// defaults to <input_dir>/<year>/dNN/input.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+        /// prints to stdout when left out
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// turn a table written by `export` back into puzzle input
+    Import {
+        #[arg(long)]
+        year: Option<u32>,
+        #[arg(long)]
+        day: u32,
+        #[arg(long)]
+        input: PathBuf,
+        /// defaults to what the input's extension says
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+        /// prints to stdout when left out
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// ask 2023 day 2's questions about the bag, of every game at once
+    Bags {
+        /// defaults to <input_dir>/2023/d02/input.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[command(subcommand)]
+        query: Bags,
+    },
+    /// encrypt or decrypt the inputs stored in the repo
+    Inputs {
+        #[command(subcommand)]
+        action: Inputs,
+    },
+    /// lay out a crate for a new day
+    New {
+        #[arg(long)]
+        year: u32,
+        #[arg(long)]
+        day: u32,
+    },
+}
+
+#[derive(Subcommand)]
+enum Bags {
+    /// the bag with the fewest cubes that makes at least k games possible
+    Smallest { k: usize },
+    /// every minimal bag that makes at least k games possible
+    Frontier { k: usize },
+    /// the games a bag makes impossible
+    RuledOut {
+        /// like "12 red, 13 green, 14 blue", which is also the default
+        bag: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum Inputs {
+    /// encrypt each day's input.txt into input.txt.enc, making a key first if
+    /// there isn't one yet
+    Encrypt {
+        /// every year when left out
+        #[arg(long)]
+        year: Option<u32>,
+        /// every day when left out
+        #[arg(long)]
+        day: Option<u32>,
+    },
+    /// write each day's input.txt.enc back out as input.txt
+    Decrypt {
+        /// every year when left out
+        #[arg(long)]
+        year: Option<u32>,
+        /// every day when left out
+        #[arg(long)]
+        day: Option<u32>,
+    },
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+/// what a job is solved by
+enum Solve<'a> {
+    Builtin(Solver),
+    Plugin(&'a PluginSolver),
+    /// reads the input from the file itself
+    Stream(StreamSolver),
+}
+
+/// a part ready to be solved, with the variant it's solved by, if any
+struct Job<'a> {
+    year: u32,
+    day: u32,
+    part: u32,
+    variant: Option<String>,
+    solve: Solve<'a>,
+    options: Options,
+    /// the file the input is from, which its mistakes are blamed on
+    input: PathBuf,
+    binding: String,
+}
+
+impl Job<'_> {
+    fn solve(&self) -> (String, Duration) {
+        let time = Instant::now();
+        let answer = match self.solve {
+            Solve::Builtin(solver) => {
+                solver(&self.binding, &self.options).unwrap_or_else(|err| self.blame(err))
+            }
+            Solve::Plugin(solver) => {
+                solver
+                    .solve(&self.binding, &self.options)
+                    .unwrap_or_else(|code| {
+                        fail(format!("{} {}", self.label(), plugins::describe(code)))
+                    })
+            }
+            Solve::Stream(solver) => {
+                solver(&mut open(&self.input), &self.options).unwrap_or_else(|err| self.blame(err))
+            }
+        };
+        (answer, time.elapsed())
+    }
+
+    /// fails with `err`, naming the input when it's the input's fault and
+    /// the part otherwise
+    fn blame(&self, err: SolveError) -> ! {
+        match err {
+            SolveError::Parse(_) | SolveError::Stream(_) => {
+                fail(format!("{}: {}", self.input.display(), err))
+            }
+            _ => fail(format!("{}: {}", self.label(), err)),
+        }
+    }
+
+    fn label(&self) -> String {
+        let mut label = format!("{} day {} part {}", self.year, self.day, self.part);
+        if let Some(variant) = &self.variant {
+            label += &format!(" ({})", variant);
+        }
+        label
+    }
+}
+
+/// loads every plugin in `plugin_dir`, warning about any that can't be
+fn load_plugins(config: &Config) -> Vec<Plugin> {
+    let (plugins, errors) = plugins::load_dir(&config.plugin_dir);
+    for err in errors {
+        eprintln!("warning: skipping plugin: {}", err);
+    }
+    plugins
+}
+
+/// every plugin solver for a day
+fn plugin_solvers(plugins: &[Plugin], year: u32, day: u32) -> Vec<&PluginSolver> {
+    plugins
+        .iter()
+        .flat_map(|plugin| &plugin.solvers)
+        .filter(|solver| solver.year == year && solver.day == day)
+        .collect()
+}
+
+/// the day's settings, or a clean exit if the `--set` overrides are malformed
+fn day_options(config: &Config, year: u32, day: u32, overrides: &[String]) -> Options {
+    config
+        .options(year, day, overrides)
+        .unwrap_or_else(|err| fail(err.to_string()))
+}
+
+/// opens an input to stream from it
+fn open(path: &Path) -> BufReader<File> {
+    let file = File::open(path)
+        .unwrap_or_else(|err| fail(format!("couldn't read {}: {}", path.display(), err)));
+    let mut reader = BufReader::with_capacity(1 << 16, file);
+
+    // streaming is for inputs too big to decrypt into memory, which encrypted
+    // ones never are
+    let head = reader
+        .fill_buf()
+        .unwrap_or_else(|err| fail(format!("couldn't read {}: {}", path.display(), err)));
+    if inputs::is_encrypted(head) {
+        fail(format!(
+            "{} is encrypted, so it can't be streamed",
+            path.display()
+        ));
+    }
+
+    reader
+}
+
+/// `jobs` for `--stream`, where the solvers read the input themselves
+fn stream_jobs<'a>(config: &Config, year: u32, day: u32, target: Target) -> Vec<Job<'a>> {
+    let Some(entry) = aoc::find_stream(year, day) else {
+        fail(format!("{} day {} can't stream its input", year, day));
+    };
+    if target.variant.is_some() {
+        fail("variants can't stream their input".to_string());
+    }
+
+    let path = target.input.unwrap_or_else(|| config.input_path(year, day));
+    let parts = match target.part {
+        Some(part) if entry.solver(part).is_none() => fail(format!("there's no part {}", part)),
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    parts
+        .into_iter()
+        .map(|part| Job {
+            year,
+            day,
+            part,
+            variant: None,
+            solve: Solve::Stream(entry.solver(part).unwrap()),
+            options: day_options(config, year, day, &target.set),
+            input: path.clone(),
+            binding: String::new(),
+        })
+        .collect()
+}
+
+/// reads the input from `path`, or the day's own input when it isn't given,
+/// and returns it with where it came from
+fn read_input(config: &Config, year: u32, day: u32, path: Option<PathBuf>) -> (PathBuf, String) {
+    // an input that's asked for has to exist as it's named, while the
+    // default one can be the encrypted copy
+    let (path, binding) = match path {
+        Some(path) => {
+            let binding = inputs::read(&path);
+            (path, binding)
+        }
+        None => {
+            let path = config.input_path(year, day);
+            let binding = inputs::load(&path);
+            (path, binding)
+        }
+    };
+    (path, binding.unwrap_or_else(|err| fail(err.to_string())))
+}
+
+/// works out which solver handles each requested part, and reads the input
+fn jobs<'a>(config: &Config, plugins: &'a [Plugin], target: Target) -> Vec<Job<'a>> {
+    let year = target.year.unwrap_or_else(aoc::default_year);
+    let day = target.day;
+    if target.stream {
+        return stream_jobs(config, year, day, target);
+    }
+
+    let entry = aoc::find(year, day);
+    let extra = plugin_solvers(plugins, year, day);
+    if entry.is_none() && extra.is_empty() {
+        fail(format!("no solution for {} day {}", year, day));
+    }
+
+    let settings = config.day(year, day);
+    let variant = target
+        .variant
+        .or_else(|| settings.and_then(|settings| settings.variant.clone()));
+    if let Some(name) = &variant {
+        let builtin =
+            entry.is_some_and(|entry| entry.variants.iter().any(|variant| variant.name == name));
+        if !builtin && !extra.iter().any(|solver| &solver.name == name) {
+            fail(format!(
+                "{} day {} has no variant called {}",
+                year, day, name
+            ));
+        }
+    }
+
+    // a variant only stands in for the parts it covers. built-in variants win
+    // over plugins with the same name
+    let pick = |part: u32| -> Option<(Option<String>, Solve<'a>)> {
+        if let Some(name) = variant.as_deref() {
+            if let Some(solver) = entry.and_then(|entry| entry.variant(part, name)) {
+                return Some((Some(name.to_string()), Solve::Builtin(solver)));
+            }
+            if let Some(solver) = extra
+                .iter()
+                .find(|solver| solver.part == part && solver.name == name)
+            {
+                return Some((Some(name.to_string()), Solve::Plugin(solver)));
+            }
+        }
+        Some((None, Solve::Builtin(entry?.solver(part)?)))
+    };
+
+    let parts = match target.part {
+        Some(part) => match pick(part) {
+            Some(picked) => vec![(part, picked)],
+            None => fail(format!("there's no part {}", part)),
+        },
+        None => [1, 2]
+            .into_iter()
+            .filter_map(|part| Some((part, pick(part)?)))
+            .collect(),
+    };
+    if parts.is_empty() {
+        fail(format!(
+            "{} day {} only has plugin solvers; pick one with --variant",
+            year, day
+        ));
+    }
+
+    let options = day_options(config, year, day, &target.set);
+    let (input, binding) = read_input(config, year, day, target.input);
+
+    parts
+        .into_iter()
+        .map(|(part, (variant, solve))| Job {
+            year,
+            day,
+            part,
+            variant,
+            solve,
+            options: options.clone(),
+            input: input.clone(),
+            binding: binding.clone(),
+        })
+        .collect()
+}
+
+/// warns on stderr when a part took longer than `time_budget_ms`
+fn warn_over_budget(config: &Config, job: &Job, elapsed: Duration) {
+    if let Some(budget) = config.time_budget_ms {
+        if elapsed > Duration::from_millis(budget) {
+            eprintln!(
+                "warning: {} took {}ms, over the {}ms budget",
+                job.label(),
+                elapsed.as_millis(),
+                budget
+            );
+        }
+    }
+}
+
+/// one line of `output = "json"`
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    year: u32,
+    day: u32,
+    part: u32,
+    variant: Option<&'a str>,
+    answer: &'a str,
+    us: u128,
+}
+
+fn run(config: &Config, target: Target) {
+    let plugins = load_plugins(config);
+    for job in jobs(config, &plugins, target) {
+        let (answer, elapsed) = job.solve();
+
+        match config.output {
+            Output::Text => println!("{}: {} ({}us)", job.label(), answer, elapsed.as_micros()),
+            Output::Json => {
+                let line = JsonAnswer {
+                    year: job.year,
+                    day: job.day,
+                    part: job.part,
+                    variant: job.variant.as_deref(),
+                    answer: &answer,
+                    us: elapsed.as_micros(),
+                };
+                println!("{}", serde_json::to_string(&line).unwrap());
+            }
+        }
+
+        warn_over_budget(config, &job, elapsed);
+    }
+}
+
+fn annotate(config: &Config, target: Target) {
+    let year = target.year.unwrap_or_else(aoc::default_year);
+    let day = target.day;
+    let Some(annotate) = aoc::find_annotate(year, day) else {
+        fail(format!("{} day {} can't annotate its input", year, day));
+    };
+    if target.stream || target.variant.is_some() || target.part.is_some() {
+        fail("--annotate covers both parts, and can't stream or run a variant".to_string());
+    }
+
+    let (input, binding) = read_input(config, year, day, target.input);
+
+    // colour only makes sense on a terminal, and https://no-color.org
+    let colour = io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+    let options = day_options(config, year, day, &target.set);
+    let annotated = annotate(&binding, &options, colour).unwrap_or_else(|err| match err {
+        SolveError::Parse(_) => fail(format!("{}: {}", input.display(), err)),
+        _ => fail(format!("{} day {}: {}", year, day, err)),
+    });
+    print!("{}", annotated);
+}
+
+fn bench(config: &Config, target: Target, iterations: Option<usize>) {
+    let iterations = iterations.unwrap_or(config.bench_iterations).max(1);
+
+    let plugins = load_plugins(config);
+    for job in jobs(config, &plugins, target) {
+        let mut times = (0..iterations)
+            .map(|_| job.solve().1)
+            .collect::<Vec<Duration>>();
+        times.sort();
+
+        let mean = times.iter().sum::<Duration>() / iterations as u32;
+        println!(
+            "{}: min {}us, median {}us, mean {}us, max {}us over {} runs",
+            job.label(),
+            times[0].as_micros(),
+            times[iterations / 2].as_micros(),
+            mean.as_micros(),
+            times[iterations - 1].as_micros(),
+            iterations
+        );
+
+        warn_over_budget(config, &job, mean);
+    }
+}
+
+fn gen(year: Option<u32>, day: u32, seed: u64, size: Option<usize>, output: Option<PathBuf>) {
+    let year = year.unwrap_or_else(aoc::default_year);
+    let Some(binding) = aoc::gen::generate(year, day, seed, size) else {
+        fail(format!("no generator for {} day {}", year, day));
+    };
+
+    write_out(output, &binding);
+}
+
+/// writes to `output`, or to stdout when there isn't one
+fn write_out(output: Option<PathBuf>, text: &str) {
+    match output {
+        Some(path) => {
+            if let Err(err) = fs::write(&path, text) {
+                fail(format!("couldn't write {}: {}", path.display(), err));
+            }
+        }
+        None => print!("{}", text),
+    }
+}
+
+fn export(
+    config: &Config,
+    year: Option<u32>,
+    day: u32,
+    input: Option<PathBuf>,
+    format: Format,
+    output: Option<PathBuf>,
+) {
+    let year = year.unwrap_or_else(aoc::default_year);
+    let binding = match input {
+        Some(path) => inputs::read(&path),
+        None => inputs::load(&config.input_path(year, day)),
+    };
+    let binding = binding.unwrap_or_else(|err| fail(err.to_string()));
+
+    let table =
+        export::export(year, day, &binding, format).unwrap_or_else(|err| fail(err.to_string()));
+    write_out(output, &table);
+}
+
+fn bags(config: &Config, input: Option<PathBuf>, query: Bags) {
+    use aoc2023_d02::{infer, Bag};
+
+    let binding = match input {
+        Some(path) => inputs::read(&path),
+        None => inputs::load(&config.input_path(2023, 2)),
+    };
+    let binding = binding.unwrap_or_else(|err| fail(err.to_string()));
+    let games = aoc2023_d02::parse(&binding).unwrap_or_else(|err| fail(err.to_string()));
+
+    match query {
+        Bags::Smallest { k } => match infer::smallest(&games, k) {
+            Ok(Some(bag)) => println!("{} ({} cubes)", bag, bag.total()),
+            Ok(None) => fail(format!("there are only {} games", games.len())),
+            Err(err) => fail(err.to_string()),
+        },
+        Bags::Frontier { k } => {
+            let frontier = infer::frontier(&games, k).unwrap_or_else(|err| fail(err.to_string()));
+            for bag in frontier {
+                println!("{} ({} cubes)", bag, bag.total());
+            }
+        }
+        Bags::RuledOut { bag } => {
+            let bag = match bag {
+                Some(spec) => Bag::parse(&spec).unwrap_or_else(|err| fail(err.to_string())),
+                None => Bag::default(),
+            };
+            for game in infer::ruled_out(&games, &bag) {
+                println!("game {}", game.id);
+            }
+        }
+    }
+}
+
+fn import(
+    year: Option<u32>,
+    day: u32,
+    input: PathBuf,
+    format: Option<Format>,
+    output: Option<PathBuf>,
+) {
+    let year = year.unwrap_or_else(aoc::default_year);
+    let format = format.or_else(|| {
+        let extension = input.extension()?.to_str()?;
+        Format::from_extension(extension)
+    });
+    let Some(format) = format else {
+        fail(format!(
+            "can't tell what format {} is in; pass --format",
+            input.display()
+        ));
+    };
+
+    let data = fs::read_to_string(&input)
+        .unwrap_or_else(|err| fail(format!("couldn't read {}: {}", input.display(), err)));
+    let binding = export::import(year, day, &data, format)
+        .unwrap_or_else(|err| fail(format!("{}: {}", input.display(), err)));
+    write_out(output, &binding);
+}
+
+/// every day matching the filters, where `None` matches anything
+fn days(year: Option<u32>, day: Option<u32>) -> impl Iterator<Item = &'static aoc::Day> {
+    aoc::DAYS.iter().filter(move |entry| {
+        year.is_none_or(|year| year == entry.year) && day.is_none_or(|day| day == entry.day)
+    })
+}
+
+fn list(config: &Config, year: Option<u32>) {
+    let plugins = load_plugins(config);
+
+    let mut keys = days(year, None)
+        .map(|entry| (entry.year, entry.day))
+        .collect::<BTreeSet<(u32, u32)>>();
+    for plugin in &plugins {
+        keys.extend(
+            plugin
+                .solvers
+                .iter()
+                .filter(|solver| year.is_none_or(|year| year == solver.year))
+                .map(|solver| (solver.year, solver.day)),
+        );
+    }
+
+    for (year, day) in keys {
+        let entry = aoc::find(year, day);
+        let mut names = entry
+            .iter()
+            .flat_map(|entry| entry.variants)
+            .map(|variant| format!("{} (part {})", variant.name, variant.part))
+            .collect::<Vec<String>>();
+        for plugin in &plugins {
+            names.extend(
+                plugin
+                    .solvers
+                    .iter()
+                    .filter(|solver| solver.year == year && solver.day == day)
+                    .map(|solver| {
+                        format!(
+                            "{} (part {}, from {})",
+                            solver.name, solver.part, plugin.name
+                        )
+                    }),
+            );
+        }
+
+        let mut line = format!("{} day {}", year, day);
+        if entry.is_none() {
+            line += " (plugins only)";
+        }
+        if !names.is_empty() {
+            line += &format!(", variants: {}", names.join(", "));
+        }
+        println!("{}", line);
+    }
+}
+
+fn encrypt(config: &Config, year: Option<u32>, day: Option<u32>) {
+    let key = match inputs::load_key() {
+        Ok(key) => key,
+        Err(inputs::Error::NoKey(path)) => {
+            let key = inputs::create_key()
+                .unwrap_or_else(|err| fail(format!("couldn't write {}: {}", path.display(), err)));
+            println!(
+                "made a new key in {}; share it with anyone who needs to run the inputs",
+                path.display()
+            );
+            key
+        }
+        Err(err) => fail(err.to_string()),
+    };
+
+    for entry in days(year, day) {
+        let plain = config.input_path(entry.year, entry.day);
+        let Ok(data) = fs::read(&plain) else {
+            continue;
+        };
+        if inputs::is_encrypted(&data) {
+            continue;
+        }
+
+        // every encryption picks a new nonce, so leave files that already
+        // hold the same input alone rather than churning them in git
+        let target = inputs::encrypted_path(&plain);
+        if let Ok(existing) = fs::read(&target) {
+            if inputs::decrypt(&key, &existing).as_deref() == Some(&data[..]) {
+                continue;
+            }
+        }
+
+        if let Err(err) = fs::write(&target, inputs::encrypt(&key, &data)) {
+            fail(format!("couldn't write {}: {}", target.display(), err));
+        }
+        println!("encrypted {}", target.display());
+    }
+}
+
+fn decrypt(config: &Config, year: Option<u32>, day: Option<u32>) {
+    for entry in days(year, day) {
+        let target = config.input_path(entry.year, entry.day);
+        let source = inputs::encrypted_path(&target);
+        if !source.exists() {
+            continue;
+        }
+        let binding = inputs::read(&source).unwrap_or_else(|err| fail(err.to_string()));
+
+        match fs::read_to_string(&target) {
+            Ok(existing) if existing == binding => continue,
+            Ok(_) => {
+                eprintln!(
+                    "{} doesn't match {}, leaving it alone",
+                    target.display(),
+                    source.display()
+                );
+                continue;
+            }
+            Err(_) => {}
+        }
+
+        if let Err(err) = fs::write(&target, binding) {
+            fail(format!("couldn't write {}: {}", target.display(), err));
+        }
+        println!("decrypted {}", target.display());
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let config = Config::load().unwrap_or_else(|err| fail(err.to_string()));
+
+    match cli.command {
+        Command::Run {
+            target,
+            annotate: false,
+        } => run(&config, target),
+        Command::Run {
+            target,
+            annotate: true,
+        } => annotate(&config, target),
+        Command::Bench { target, iterations } => bench(&config, target, iterations),
+        Command::List { year } => list(&config, year),
+        Command::Gen {
+            year,
+            day,
+            seed,
+            size,
+            output,
+        } => gen(year, day, seed, size, output),
+        Command::Export {
+            year,
+            day,
+            input,
+            format,
+            output,
+        } => export(&config, year, day, input, format, output),
+        Command::Import {
+            year,
+            day,
+            input,
+            format,
+            output,
+        } => import(year, day, input, format, output),
+        Command::Bags { input, query } => bags(&config, input, query),
+        Command::Inputs { action } => match action {
+            Inputs::Encrypt { year, day } => encrypt(&config, year, day),
+            Inputs::Decrypt { year, day } => decrypt(&config, year, day),
+        },
+        Command::New { year, day } => {
+            if let Err(err) = scaffold::new_day(year, day) {
+                fail(format!("couldn't create {} day {}: {}", year, day, err));
+            }
+        }
+    }
+}
@@ -0,0 +1,410 @@
+//! solvers loaded at runtime from shared libraries, so experimental solutions
+//! can live in their own crates (or their own language) without touching this
+//! repo. see `AocPlugin` in `ffi` for what a plugin has to export. every
+//! solver a plugin registers shows up as a variant of its day.
+
+use std::ffi::{c_char, CStr};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use libloading::{Library, Symbol};
+
+use crate::ffi::{self, AocPlugin, AocPluginSolve, AOC_PLUGIN_ABI_VERSION};
+use crate::Options;
+
+/// the symbol every plugin exports
+const REGISTER: &[u8] = b"aoc_plugin_register";
+
+/// answers longer than this are treated as a broken plugin, not retried
+const MAX_ANSWER: usize = 1 << 20;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, io::Error),
+    Load(PathBuf, libloading::Error),
+    /// the plugin returned null from `aoc_plugin_register`, or registered
+    /// with a different abi version
+    Version(PathBuf, Option<u32>),
+    Malformed(PathBuf, &'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(path, err) => write!(f, "couldn't read {}: {}", path.display(), err),
+            // libloading's errors already name the file
+            Error::Load(_, err) => write!(f, "couldn't load plugin: {}", err),
+            Error::Version(path, None) => write!(
+                f,
+                "{} doesn't support plugin abi version {}",
+                path.display(),
+                AOC_PLUGIN_ABI_VERSION
+            ),
+            Error::Version(path, Some(version)) => write!(
+                f,
+                "{} was built for plugin abi version {}, but the runner speaks {}",
+                path.display(),
+                version,
+                AOC_PLUGIN_ABI_VERSION
+            ),
+            Error::Malformed(path, reason) => write!(f, "{}: {}", path.display(), reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// one solver from a plugin, run as a variant called `name`
+pub struct PluginSolver {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub name: String,
+    solve: AocPluginSolve,
+}
+
+impl PluginSolver {
+    /// solves the part, or returns the plugin's `AOC_ERR_*` code
+    pub fn solve(&self, input: &str, options: &Options) -> Result<String, i32> {
+        let options = options.to_string();
+        let mut out = vec![0_u8; 64];
+
+        loop {
+            // SAFETY: the pointers and lengths all come from live slices, and
+            // the plugin promised its solver follows `aoc_solve`'s rules
+            let code = unsafe {
+                (self.solve)(
+                    input.as_ptr(),
+                    input.len(),
+                    options.as_ptr(),
+                    options.len(),
+                    out.as_mut_ptr(),
+                    out.len(),
+                )
+            };
+
+            match code {
+                ffi::AOC_OK => break,
+                ffi::AOC_ERR_BUFFER if out.len() < MAX_ANSWER => out.resize(out.len() * 2, 0),
+                code => return Err(code),
+            }
+        }
+
+        let len = out
+            .iter()
+            .position(|&byte| byte == 0)
+            .ok_or(ffi::AOC_ERR_BUFFER)?;
+        out.truncate(len);
+        String::from_utf8(out).map_err(|_| ffi::AOC_ERR_UTF8)
+    }
+}
+
+/// a loaded plugin and everything it registered
+pub struct Plugin {
+    pub name: String,
+    pub path: PathBuf,
+    pub solvers: Vec<PluginSolver>,
+    // the solvers point into the library, so it has to stay loaded for as
+    // long as they're around
+    _library: Library,
+}
+
+/// copies out a nul-terminated string the plugin handed over
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a nul-terminated string
+unsafe fn string(path: &Path, ptr: *const c_char) -> Result<String, Error> {
+    if ptr.is_null() {
+        return Err(Error::Malformed(path.to_path_buf(), "missing name"));
+    }
+
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map(str::to_string)
+        .map_err(|_| Error::Malformed(path.to_path_buf(), "name isn't valid utf-8"))
+}
+
+impl Plugin {
+    /// loads the plugin at `path` and asks it what it solves
+    pub fn load(path: &Path) -> Result<Plugin, Error> {
+        let error = |reason| Error::Malformed(path.to_path_buf(), reason);
+
+        // SAFETY: loading a library runs its initializers. there's no way to
+        // check those, so plugins are trusted as much as the runner itself
+        let library =
+            unsafe { Library::new(path) }.map_err(|err| Error::Load(path.to_path_buf(), err))?;
+
+        let (name, solvers) = unsafe {
+            let register: Symbol<unsafe extern "C" fn(u32) -> *const AocPlugin> = library
+                .get(REGISTER)
+                .map_err(|err| Error::Load(path.to_path_buf(), err))?;
+
+            let plugin = register(AOC_PLUGIN_ABI_VERSION);
+            let Some(plugin) = plugin.as_ref() else {
+                return Err(Error::Version(path.to_path_buf(), None));
+            };
+            if plugin.abi_version != AOC_PLUGIN_ABI_VERSION {
+                return Err(Error::Version(path.to_path_buf(), Some(plugin.abi_version)));
+            }
+
+            let solvers = match plugin.solver_count {
+                0 => &[],
+                _ if plugin.solvers.is_null() => return Err(error("missing solvers")),
+                count => std::slice::from_raw_parts(plugin.solvers, count),
+            };
+
+            let solvers = solvers
+                .iter()
+                .map(|solver| {
+                    Ok(PluginSolver {
+                        year: solver.year,
+                        day: solver.day,
+                        part: solver.part,
+                        name: string(path, solver.name)?,
+                        solve: solver.solve,
+                    })
+                })
+                .collect::<Result<Vec<PluginSolver>, Error>>()?;
+
+            (string(path, plugin.name)?, solvers)
+        };
+
+        if solvers.iter().any(|solver| !(1..=2).contains(&solver.part)) {
+            return Err(error("solvers can only be for part 1 or 2"));
+        }
+
+        Ok(Plugin {
+            name,
+            path: path.to_path_buf(),
+            solvers,
+            _library: library,
+        })
+    }
+}
+
+/// loads every shared library in `dir`, in name order. a plugin that fails to
+/// load doesn't stop the rest, so its error is returned alongside them. a
+/// missing directory just means there aren't any plugins
+pub fn load_dir(dir: &Path) -> (Vec<Plugin>, Vec<Error>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return (Vec::new(), Vec::new()),
+        Err(err) => return (Vec::new(), vec![Error::Io(dir.to_path_buf(), err)]),
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == std::env::consts::DLL_EXTENSION)
+        })
+        .collect::<Vec<PathBuf>>();
+    paths.sort();
+
+    let mut plugins = Vec::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        match Plugin::load(&path) {
+            Ok(plugin) => plugins.push(plugin),
+            Err(err) => errors.push(err),
+        }
+    }
+
+    (plugins, errors)
+}
+
+/// describes one of the `AOC_ERR_*` codes a solver can fail with
+pub fn describe(code: i32) -> &'static str {
+    match code {
+        ffi::AOC_ERR_NULL => "got a null pointer",
+        ffi::AOC_ERR_UNKNOWN => "doesn't know how to solve it",
+        ffi::AOC_ERR_UTF8 => "got or gave invalid utf-8",
+        ffi::AOC_ERR_PANIC => "panicked",
+        ffi::AOC_ERR_BUFFER => "gave an answer that's far too long",
+        ffi::AOC_ERR_INPUT => "couldn't read the input",
+        ffi::AOC_ERR_ANSWER => "has no answer to give",
+        _ => "failed with an unknown error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// copies `answer` and its nul into `out`, the way a plugin's solver would
+    unsafe fn write(answer: &[u8], out_buf: *mut u8, out_len: usize) -> i32 {
+        if answer.len() + 1 > out_len {
+            return ffi::AOC_ERR_BUFFER;
+        }
+        let out = std::slice::from_raw_parts_mut(out_buf, out_len);
+        out[..answer.len()].copy_from_slice(answer);
+        out[answer.len()] = 0;
+        ffi::AOC_OK
+    }
+
+    /// answers with how long the input is
+    unsafe extern "C" fn length(
+        _input_ptr: *const u8,
+        len: usize,
+        _options_ptr: *const u8,
+        _options_len: usize,
+        out_buf: *mut u8,
+        out_len: usize,
+    ) -> i32 {
+        write(len.to_string().as_bytes(), out_buf, out_len)
+    }
+
+    /// answers with the options it was handed
+    unsafe extern "C" fn echo_options(
+        _input_ptr: *const u8,
+        _len: usize,
+        options_ptr: *const u8,
+        options_len: usize,
+        out_buf: *mut u8,
+        out_len: usize,
+    ) -> i32 {
+        let options = std::slice::from_raw_parts(options_ptr, options_len);
+        write(options, out_buf, out_len)
+    }
+
+    /// answers with far more than the first buffer holds
+    unsafe extern "C" fn long(
+        _input_ptr: *const u8,
+        _len: usize,
+        _options_ptr: *const u8,
+        _options_len: usize,
+        out_buf: *mut u8,
+        out_len: usize,
+    ) -> i32 {
+        write(&[b'7'; 1000], out_buf, out_len)
+    }
+
+    /// always wants a bigger buffer
+    unsafe extern "C" fn greedy(
+        _input_ptr: *const u8,
+        _len: usize,
+        _options_ptr: *const u8,
+        _options_len: usize,
+        _out_buf: *mut u8,
+        _out_len: usize,
+    ) -> i32 {
+        ffi::AOC_ERR_BUFFER
+    }
+
+    unsafe extern "C" fn unknown(
+        _input_ptr: *const u8,
+        _len: usize,
+        _options_ptr: *const u8,
+        _options_len: usize,
+        _out_buf: *mut u8,
+        _out_len: usize,
+    ) -> i32 {
+        ffi::AOC_ERR_UNKNOWN
+    }
+
+    unsafe extern "C" fn not_utf8(
+        _input_ptr: *const u8,
+        _len: usize,
+        _options_ptr: *const u8,
+        _options_len: usize,
+        out_buf: *mut u8,
+        out_len: usize,
+    ) -> i32 {
+        write(&[0xff, 0xfe], out_buf, out_len)
+    }
+
+    /// claims it's done without ever writing a nul
+    unsafe extern "C" fn unterminated(
+        _input_ptr: *const u8,
+        _len: usize,
+        _options_ptr: *const u8,
+        _options_len: usize,
+        out_buf: *mut u8,
+        out_len: usize,
+    ) -> i32 {
+        std::slice::from_raw_parts_mut(out_buf, out_len).fill(b'1');
+        ffi::AOC_OK
+    }
+
+    fn solver(solve: AocPluginSolve) -> PluginSolver {
+        PluginSolver {
+            year: 2023,
+            day: 1,
+            part: 1,
+            name: "test".to_string(),
+            solve,
+        }
+    }
+
+    #[test]
+    fn answers() {
+        let options = Options::new();
+        assert_eq!(solver(length).solve("abc\n", &options), Ok("4".to_string()));
+
+        let mut options = Options::new();
+        options.insert("strict".to_string(), true.into());
+        assert_eq!(
+            solver(echo_options).solve("", &options),
+            Ok("strict = true\n".to_string())
+        );
+
+        // the buffer keeps doubling until the answer fits
+        let answer = solver(long).solve("", &Options::new()).unwrap();
+        assert_eq!(answer, "7".repeat(1000));
+    }
+
+    #[test]
+    fn failures() {
+        let options = Options::new();
+        assert_eq!(
+            solver(unknown).solve("", &options),
+            Err(ffi::AOC_ERR_UNKNOWN)
+        );
+        assert_eq!(solver(not_utf8).solve("", &options), Err(ffi::AOC_ERR_UTF8));
+        assert_eq!(
+            solver(unterminated).solve("", &options),
+            Err(ffi::AOC_ERR_BUFFER)
+        );
+        // which stops growing somewhere, rather than using all the memory
+        assert_eq!(solver(greedy).solve("", &options), Err(ffi::AOC_ERR_BUFFER));
+
+        assert_eq!(
+            describe(ffi::AOC_ERR_UNKNOWN),
+            "doesn't know how to solve it"
+        );
+        assert_eq!(
+            describe(ffi::AOC_ERR_BUFFER),
+            "gave an answer that's far too long"
+        );
+        assert_eq!(describe(42), "failed with an unknown error");
+    }
+
+    #[test]
+    fn loading_a_dir() {
+        let dir = std::env::temp_dir().join(format!("aoc-plugins-{}", std::process::id()));
+
+        // no directory is the same as an empty one
+        let (plugins, errors) = load_dir(&dir);
+        assert!(plugins.is_empty() && errors.is_empty());
+
+        // only libraries are loaded, and one that isn't really a library is
+        // an error for that file alone
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("notes.txt"), "not a plugin").unwrap();
+        let broken = dir.join(format!("broken.{}", std::env::consts::DLL_EXTENSION));
+        fs::write(&broken, "not a library either").unwrap();
+
+        let (plugins, errors) = load_dir(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(plugins.is_empty());
+        assert!(
+            matches!(&errors[..], [Error::Load(path, _)] if *path == broken),
+            "{:?}",
+            errors
+        );
+        assert!(errors[0].to_string().starts_with("couldn't load plugin: "));
+    }
+}
@@ -0,0 +1,73 @@
+use std::fs;
+use std::io;
+
+const LIB: &str = "pub fn part1(binding: &str) -> i32 {
+    let input = binding.split('\\n').collect::<Vec<&str>>();
+
+    todo!(\"{} lines\", input.len())
+}
+
+pub fn part2(binding: &str) -> i32 {
+    let input = binding.split('\\n').collect::<Vec<&str>>();
+
+    todo!(\"{} lines\", input.len())
+}
+";
+
+fn manifest(year: u32, day: u32) -> String {
+    format!(
+        "[package]
+name = \"aoc{year}-d{day:02}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+
+[[bin]]
+name = \"{year}-d{day:02}-p1\"
+path = \"src/p1.rs\"
+
+[[bin]]
+name = \"{year}-d{day:02}-p2\"
+path = \"src/p2.rs\"
+"
+    )
+}
+
+fn bin(year: u32, day: u32, part: u32) -> String {
+    format!(
+        "fn main() {{
+    let binding = std::fs::read_to_string(\"input.txt\").unwrap();
+
+    println!(\"{{}}\", aoc{year}_d{day:02}::part{part}(&binding));
+}}
+"
+    )
+}
+
+/// lays out a new day's crate in `<year>/dNN`, matching the existing days
+pub fn new_day(year: u32, day: u32) -> io::Result<()> {
+    let dir = aoc::day_dir(year, day);
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), manifest(year, day))?;
+    fs::write(dir.join("src/lib.rs"), LIB)?;
+    fs::write(dir.join("src/p1.rs"), bin(year, day, 1))?;
+    fs::write(dir.join("src/p2.rs"), bin(year, day, 2))?;
+    fs::write(dir.join("src/README.md"), format!("# Day {}\n", day))?;
+
+    println!("created {}", dir.display());
+    println!("to run it through the runner, add these to aoc:");
+    println!("  aoc/Cargo.toml:   aoc{year}-d{day:02} = {{ path = \"../{year}/d{day:02}\" }}");
+    println!("  aoc/src/lib.rs:   day!({year}, {day}, aoc{year}_d{day:02}),");
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! `aoc export` followed by `aoc import` should give back the input it
+//! started from, which also checks d02's parser against its own output.
+
+use aoc::export::{export, import, Format};
+
+fn round_trip(binding: &str, format: Format) -> String {
+    let table = export(2023, 2, binding, format).unwrap();
+    import(2023, 2, &table, format).unwrap()
+}
+
+#[test]
+fn generated_inputs() {
+    for seed in 0..20 {
+        let binding = aoc::gen::generate(2023, 2, seed, None).unwrap();
+        for format in [Format::Csv, Format::Json] {
+            assert_eq!(round_trip(&binding, format), binding, "seed {}", seed);
+        }
+    }
+}
+
+#[test]
+fn odd_colours() {
+    // colours can be any word, including ones csv has to quote
+    let binding = "Game 7: 2 \"red\", 1 magenta; 3 ol'blue\nGame 3: 1 x\n";
+    for format in [Format::Csv, Format::Json] {
+        assert_eq!(round_trip(binding, format), binding);
+    }
+}
+
+#[test]
+fn rows_in_any_order() {
+    let csv = "game,round,colour,count\r\n2,1,red,1\r\n1,2,blue,4\r\n\r\n1,1,green,2\r\n";
+    assert_eq!(
+        import(2023, 2, csv, Format::Csv).unwrap(),
+        "Game 2: 1 red\nGame 1: 2 green; 4 blue\n"
+    );
+}
+
+#[test]
+fn bad_tables() {
+    let missing = "game,round,colour,count\n1,1,red,3\n1,1,blue,1\n1,3,red,4\n";
+    assert_eq!(
+        import(2023, 2, missing, Format::Csv)
+            .unwrap_err()
+            .to_string(),
+        "csv line 2: game 1 has no rows for round 2"
+    );
+
+    // a round further on than the game has rows for is missing some before
+    // it, however far on it is
+    let far = "game,round,colour,count\n1,1,red,3\n1,18446744073709551615,red,1\n";
+    assert_eq!(
+        import(2023, 2, far, Format::Csv).unwrap_err().to_string(),
+        "csv line 3: game 1 only has 2 rows, so it can't have a round 18446744073709551615"
+    );
+
+    let twice = r#"[{"game":1,"round":1,"colour":"red","count":1},
+        {"game":1,"round":1,"colour":"red","count":2}]"#;
+    assert_eq!(
+        import(2023, 2, twice, Format::Json)
+            .unwrap_err()
+            .to_string(),
+        "json row 2: game 1 draws red twice in round 1"
+    );
+
+    let header = "game,colour,count\n1,red,3\n";
+    assert!(import(2023, 2, header, Format::Csv).is_err());
+}
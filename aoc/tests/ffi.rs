@@ -0,0 +1,51 @@
+//! what `aoc_solve` hands back, and the code for each way it can fail
+
+use aoc::ffi::*;
+
+/// calls `aoc_solve` with room for `room` bytes of answer, returning the
+/// code and whatever it wrote
+fn solve(day: u32, part: u32, input: &[u8], room: usize) -> (i32, String) {
+    let mut out = vec![0_u8; room];
+    // SAFETY: both pointers come from live slices of the given lengths
+    let code = unsafe {
+        aoc_solve(
+            day,
+            part,
+            input.as_ptr(),
+            input.len(),
+            out.as_mut_ptr(),
+            out.len(),
+        )
+    };
+    let len = out.iter().position(|&byte| byte == 0).unwrap_or(0);
+    (code, String::from_utf8_lossy(&out[..len]).into_owned())
+}
+
+#[test]
+fn answers() {
+    let cards = b"Card 1: 1 2 3 | 1 2 4\n";
+    assert_eq!(solve(4, 1, cards, 64), (AOC_OK, "2".to_string()));
+    // the answer and its nul only just fit
+    assert_eq!(solve(4, 1, cards, 2), (AOC_OK, "2".to_string()));
+    assert_eq!(solve(4, 1, cards, 1), (AOC_ERR_BUFFER, String::new()));
+}
+
+#[test]
+fn failures() {
+    assert_eq!(solve(26, 1, b"", 64).0, AOC_ERR_UNKNOWN);
+    assert_eq!(solve(4, 3, b"", 64).0, AOC_ERR_UNKNOWN);
+    assert_eq!(solve(4, 1, b"\xff\n", 64).0, AOC_ERR_UTF8);
+
+    // input that doesn't parse, from a day that says so rather than panicking
+    assert_eq!(solve(2, 1, b"Game x: 3 red\n", 64).0, AOC_ERR_INPUT);
+    assert_eq!(solve(3, 2, b"*.\n..99999999999\n", 64).0, AOC_ERR_INPUT);
+
+    // two gears whose ratios add up to more than a u64 holds
+    let huge = b"4000000000*4000000000\n.....................\n4000000000*4000000000\n";
+    assert_eq!(solve(3, 2, huge, 64).0, AOC_ERR_ANSWER);
+
+    let mut out = [0_u8; 8];
+    // SAFETY: a null input is exactly what's being checked for
+    let code = unsafe { aoc_solve(1, 1, std::ptr::null(), 0, out.as_mut_ptr(), out.len()) };
+    assert_eq!(code, AOC_ERR_NULL);
+}
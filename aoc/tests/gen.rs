@@ -0,0 +1,86 @@
+//! every generator's output should be an input its day can solve, however
+//! small or big it's asked to be
+
+use std::panic;
+
+/// the sizes to try, with `None` for the day's default
+const SIZES: [Option<usize>; 4] = [Some(0), Some(1), Some(2), None];
+
+/// a size for each day well past its official input, as `(day, size)`. d06
+/// can't go past 4 races, and the grids are a side, so they grow squared
+const LARGE: [(u32, usize); 10] = [
+    (1, 100_000),
+    (2, 10_000),
+    (3, 500),
+    (4, 20_000),
+    (5, 1_000),
+    (6, 4),
+    (7, 20_000),
+    (8, 20_000),
+    (9, 20_000),
+    (10, 500),
+];
+
+/// solves both parts of `binding`, failing on anything but an answer
+fn solves(day: u32, size: Option<usize>, binding: &str) {
+    for part in [1, 2] {
+        let solved = panic::catch_unwind(|| aoc::solve(2023, day, part, binding));
+        // big inputs are too long to be any use in a failure message
+        let shown = if binding.len() < 10_000 { binding } else { "" };
+        assert!(
+            matches!(solved, Ok(Some(Ok(_)))),
+            "day {} part {} at size {:?}:\n{}",
+            day,
+            part,
+            size,
+            shown
+        );
+    }
+}
+
+#[test]
+fn generated_inputs_solve() {
+    for day in 1..=10 {
+        for size in SIZES {
+            let binding = aoc::gen::generate(2023, day, 0, size)
+                .unwrap_or_else(|| panic!("no generator for day {}", day));
+            solves(day, size, &binding);
+        }
+    }
+}
+
+#[test]
+fn large_inputs_solve() {
+    for (day, size) in LARGE {
+        let binding = aoc::gen::generate(2023, day, 1, Some(size)).unwrap();
+        solves(day, Some(size), &binding);
+    }
+}
+
+/// (rows, columns) of a grid
+fn dimensions(binding: &str) -> (usize, usize) {
+    let rows = binding.lines().collect::<Vec<&str>>();
+    let cols = rows
+        .iter()
+        .map(|row| row.chars().count())
+        .max()
+        .unwrap_or(0);
+    assert!(rows.iter().all(|row| row.chars().count() == cols));
+    (rows.len(), cols)
+}
+
+#[test]
+fn grids_are_square() {
+    for size in [7, 8, 9, 20, 21, 140] {
+        for day in [3, 10] {
+            let binding = aoc::gen::generate(2023, day, 0, Some(size)).unwrap();
+            assert_eq!(dimensions(&binding), (size, size), "day {}", day);
+        }
+    }
+
+    // a pipe loop needs some room, so d10 won't go below 7 by 7
+    for size in [0, 1, 2, 6] {
+        let binding = aoc::gen::generate(2023, 10, 0, Some(size)).unwrap();
+        assert_eq!(dimensions(&binding), (7, 7), "size {}", size);
+    }
+}
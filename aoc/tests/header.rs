@@ -0,0 +1,25 @@
+//! `include/aoc.h` is checked in for C code to use, but build.rs only ever
+//! writes the header into `OUT_DIR`. this fails when the checked-in copy has
+//! fallen behind the crate; run with `UPDATE_SNAPSHOTS=1` to bring it up to
+//! date.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn header_is_current() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc.h");
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, aoc::ffi::HEADER).unwrap();
+        return;
+    }
+
+    let checked_in = fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        checked_in == aoc::ffi::HEADER,
+        "{} is out of date; run `UPDATE_SNAPSHOTS=1 cargo test -p aoc --test header`",
+        path.display()
+    );
+}
@@ -0,0 +1,26 @@
+//! replays every input the fuzzers have caught, saved under
+//! `fuzz/regressions/<year>-dNN/`. the fuzz crate is its own workspace, so
+//! this is what keeps them running with everything else
+
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn regressions() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fuzz/regressions");
+
+    for target in fs::read_dir(root).unwrap() {
+        let target = target.unwrap().path();
+        let name = target.file_name().unwrap().to_str().unwrap().to_string();
+        let (year, day) = name.split_once("-d").unwrap();
+        let (year, day) = (year.parse().unwrap(), day.parse().unwrap());
+
+        for case in fs::read_dir(&target).unwrap() {
+            let case = case.unwrap().path();
+            println!("{}", case.display());
+            let data = fs::read(&case).unwrap();
+            aoc::fuzz::parse(year, day, &data);
+            aoc::fuzz::solve(year, day, &data);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! snapshot tests for the human-readable output some days can print along the
+//! way, so changes that happen to leave the final answer alone still show up.
+//! each test renders an input from `snapshots/inputs/` and compares it to
+//! `snapshots/<name>.snap`.
+//!
+//! when a snapshot doesn't match, the new output is written next to it as
+//! `<name>.snap.new` for review. run with `UPDATE_SNAPSHOTS=1` to accept every
+//! new output instead.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots")
+}
+
+fn input(name: &str) -> String {
+    let path = dir().join("inputs").join(name);
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {}", path.display(), err))
+}
+
+/// the differing lines between two outputs, at most a screenful of them
+fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<&str>>();
+    let actual = actual.lines().collect::<Vec<&str>>();
+
+    let mut out = String::new();
+    let mut shown = 0;
+    for idx in 0..expected.len().max(actual.len()) {
+        let (old, new) = (expected.get(idx), actual.get(idx));
+        if old == new {
+            continue;
+        }
+
+        shown += 1;
+        if shown > 20 {
+            out += "  ...\n";
+            break;
+        }
+
+        out += &format!("  line {}:\n", idx + 1);
+        if let Some(old) = old {
+            out += &format!("  - {}\n", old);
+        }
+        if let Some(new) = new {
+            out += &format!("  + {}\n", new);
+        }
+    }
+    out
+}
+
+fn check(name: &str, actual: &str) {
+    let path = dir().join(format!("{}.snap", name));
+    let pending = dir().join(format!("{}.snap.new", name));
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).unwrap();
+        let _ = fs::remove_file(&pending);
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).ok();
+    if expected.as_deref() == Some(actual) {
+        let _ = fs::remove_file(&pending);
+        return;
+    }
+
+    fs::write(&pending, actual).unwrap();
+    match expected {
+        Some(expected) => panic!(
+            "{} doesn't match; the new output is in {}\n{}\nrerun with UPDATE_SNAPSHOTS=1 to accept it",
+            path.display(),
+            pending.display(),
+            diff(&expected, actual)
+        ),
+        None => panic!(
+            "there's no {} yet; the new output is in {}\nrerun with UPDATE_SNAPSHOTS=1 to accept it",
+            path.display(),
+            pending.display()
+        ),
+    }
+}
+
+#[test]
+fn d01_annotate() {
+    let binding = input("2023-d01.txt");
+    let vocabulary = aoc2023_d01::vocabulary::Vocabulary::default();
+
+    check(
+        "2023-d01-annotate",
+        &aoc2023_d01::annotate::annotate(&binding, &vocabulary, false),
+    );
+}
+
+#[test]
+fn d03_gears() {
+    let binding = input("2023-d03.txt");
+
+    check(
+        "2023-d03-gears",
+        &aoc2023_d03::report(&binding, &aoc2023_d03::Rules::default()).unwrap(),
+    );
+}
+
+#[test]
+fn d05_chains() {
+    let binding = input("2023-d05.txt");
+
+    check("2023-d05-chains", &aoc2023_d05::d01_dumb::explain(&binding));
+}
+
+#[test]
+fn d07_part1_hands() {
+    let binding = input("2023-d07.txt");
+    let hands = aoc2023_d07::part1_hands(&binding).unwrap();
+
+    check("2023-d07-part1-hands", &aoc2023_d07::listing(&hands));
+}
+
+#[test]
+fn d07_part2_hands() {
+    let binding = input("2023-d07.txt");
+    let hands = aoc2023_d07::part2_hands(&binding).unwrap();
+
+    check("2023-d07-part2-hands", &aoc2023_d07::listing(&hands));
+}
+
+fn d10_loop(name: &str) {
+    let binding = input(&format!("2023-d10-{}.txt", name));
+    let walk = aoc2023_d10::walk(&binding).unwrap();
+
+    check(
+        &format!("2023-d10-loop-{}", name),
+        &aoc2023_d10::render(&walk),
+    );
+}
+
+#[test]
+fn d10_loop_small() {
+    d10_loop("small");
+}
+
+#[test]
+fn d10_loop_large() {
+    d10_loop("large");
+}
@@ -0,0 +1,99 @@
+//! every day that streams its input should get the same answers as it does
+//! from the whole input, and say which line it couldn't read
+
+use aoc::{Options, SolveError, Stream, STREAMS};
+use scan::StreamError;
+
+/// the sizes to try, with `None` for the day's default
+const SIZES: [Option<usize>; 3] = [Some(1), Some(40), None];
+
+fn streamed(stream: &Stream, part: u32, input: &[u8]) -> Result<String, SolveError> {
+    let mut reader = input;
+    (stream.solver(part).unwrap())(&mut reader, &Options::new())
+}
+
+fn whole(stream: &Stream, part: u32, input: &str) -> Result<String, SolveError> {
+    let day = aoc::find(stream.year, stream.day).unwrap();
+    (day.solver(part).unwrap())(input, &Options::new())
+}
+
+/// `binding` with its second line swapped for `line`
+fn second_line(binding: &str, line: &str) -> String {
+    let mut lines = binding.lines().collect::<Vec<&str>>();
+    lines[1] = line;
+    lines.join("\n") + "\n"
+}
+
+#[test]
+fn same_answers() {
+    for stream in STREAMS {
+        for seed in 0..3 {
+            for size in SIZES {
+                let binding = aoc::gen::generate(stream.year, stream.day, seed, size).unwrap();
+                for part in [1, 2] {
+                    let expected = whole(stream, part, &binding).unwrap();
+                    assert_eq!(
+                        streamed(stream, part, binding.as_bytes()).unwrap(),
+                        expected,
+                        "day {} part {} seed {} at size {:?}",
+                        stream.day,
+                        part,
+                        seed,
+                        size
+                    );
+
+                    // the last line doesn't need its newline
+                    let trimmed = binding.trim_end().as_bytes();
+                    assert_eq!(streamed(stream, part, trimmed).unwrap(), expected);
+                }
+            }
+        }
+    }
+}
+
+/// a line each day can't read. d09 reads a line without numbers as an empty
+/// sequence, so all it can trip over is a number too big for it
+fn unreadable(day: u32) -> &'static str {
+    match day {
+        9 => "1 2 99999999999999999999",
+        _ => "?!",
+    }
+}
+
+#[test]
+fn errors_name_their_line() {
+    for stream in STREAMS {
+        let binding = aoc::gen::generate(stream.year, stream.day, 0, Some(5)).unwrap();
+        let broken = second_line(&binding, unreadable(stream.day));
+        for part in [1, 2] {
+            let err = streamed(stream, part, broken.as_bytes()).unwrap_err();
+            assert!(
+                matches!(&err, SolveError::Stream(StreamError::Parse(err)) if err.line == 2),
+                "day {} part {}: {}",
+                stream.day,
+                part,
+                err
+            );
+            assert!(err.to_string().starts_with("line 2: "), "{}", err);
+        }
+    }
+}
+
+#[test]
+fn not_utf8() {
+    for stream in STREAMS {
+        let binding = aoc::gen::generate(stream.year, stream.day, 0, Some(5)).unwrap();
+        let mut broken = binding.clone().into_bytes();
+        broken.insert(binding.find('\n').unwrap() + 1, 0xff);
+        for part in [1, 2] {
+            let err = streamed(stream, part, &broken).unwrap_err();
+            assert!(
+                matches!(err, SolveError::Stream(StreamError::Io(_))),
+                "day {} part {}: {}",
+                stream.day,
+                part,
+                err
+            );
+        }
+    }
+}
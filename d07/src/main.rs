@@ -1,108 +0,0 @@
-use std::collections::HashMap;
-
-fn main() {
-    let binding = std::fs::read_to_string("input.txt").unwrap();
-    let input = binding.split("\n").collect::<Vec<&str>>();
-
-    let card_arr = vec![
-        'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A',
-    ];
-    let mut rank_map = std::collections::HashMap::new();
-    rank_map.insert((0, 5), 0);
-    rank_map.insert((1, 4), 1);
-    rank_map.insert((2, 3), 2);
-    rank_map.insert((1, 3), 3);
-    rank_map.insert((2, 2), 4);
-    rank_map.insert((1, 2), 5);
-    rank_map.insert((1, 1), 6);
-
-    let mut hands = Vec::new();
-
-    for line in input {
-        if line.len() == 0 {
-            continue;
-        }
-
-        let hand = line.split(' ').collect::<Vec<&str>>()[0].trim();
-        let bid_str = line.split(' ').collect::<Vec<&str>>()[1].trim();
-
-        let bid = bid_str.parse::<i32>().unwrap();
-
-        let mut uniques: HashMap<char, i32> = std::collections::HashMap::new();
-        let mut pairs = std::collections::HashMap::new();
-        let mut joker_count = 0;
-
-        for card in hand.chars() {
-            // if the card is a joker, add to joker count and skip
-            if card == 'J' {
-                joker_count += 1;
-                continue;
-            }
-            if uniques.contains_key(&card) {
-                pairs.insert(card, 1);
-                continue;
-            } else {
-                uniques.insert(card, 1);
-            }
-        }
-
-        match rank_map.get(&(pairs.len(), uniques.len())) {
-            Some(hand_rank) => hands.push((hand, bid, *hand_rank)),
-            None => {
-                if pairs.len() == 0 {
-                    if joker_count == 5 || joker_count == 4 {
-                        hands.push((hand, bid, 6));
-                    } else if joker_count == 3 {
-                        hands.push((hand, bid, 5));
-                    } else if joker_count == 2 {
-                        hands.push((hand, bid, 3));
-                    } else if joker_count == 1 {
-                        hands.push((hand, bid, 1));
-                    } else {
-                        hands.push((hand, bid, 0));
-                    }
-                } else if pairs.len() == 1 {
-                    if joker_count == 3 {
-                        hands.push((hand, bid, 6));
-                    } else if joker_count == 2 {
-                        hands.push((hand, bid, 5));
-                    } else if joker_count == 1 {
-                        hands.push((hand, bid, 3));
-                    }
-                } else if pairs.len() == 2 {
-                    if joker_count == 1 {
-                        hands.push((hand, bid, 4));
-                    }
-                }
-            }
-        }
-    }
-
-    hands.sort_by(|a, b| {
-        if a.2.cmp(&b.2) == std::cmp::Ordering::Equal {
-            for idx in 0..a.0.len() {
-                let a_card = a.0.chars().collect::<Vec<char>>()[idx];
-                let b_card = b.0.chars().collect::<Vec<char>>()[idx];
-
-                let a_card_rank = card_arr.iter().position(|&r| r == a_card).unwrap() + 1;
-                let b_card_rank = card_arr.iter().position(|&r| r == b_card).unwrap() + 1;
-
-                if a_card_rank > b_card_rank {
-                    return std::cmp::Ordering::Greater;
-                } else if a_card_rank < b_card_rank {
-                    return std::cmp::Ordering::Less;
-                }
-            }
-        }
-
-        return a.2.cmp(&b.2);
-    });
-
-    let mut sum: i64 = 0;
-    for (idx, hand_tuple) in hands.iter().enumerate() {
-        println!("{} {} {}", hand_tuple.0, hand_tuple.1, hand_tuple.2);
-        sum += (idx as i64 + 1) * hand_tuple.1 as i64;
-    }
-
-    println!("{}", sum);
-}
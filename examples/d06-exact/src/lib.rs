@@ -0,0 +1,167 @@
+//! an example plugin for the `aoc` runner: day 6 in integers only, as an
+//! `exact` variant of both parts. the quadratic in the day's crate goes
+//! through `f64`, which stops being exact once the race gets long enough;
+//! this binary searches for the shortest winning hold instead.
+//!
+//! the types below mirror `AocPlugin` and `AocPluginSolver` from
+//! `aoc/include/aoc.h`, the same as any plugin written outside the repo would.
+
+use std::ffi::c_char;
+use std::panic;
+use std::slice;
+
+const AOC_PLUGIN_ABI_VERSION: u32 = 1;
+
+const AOC_OK: i32 = 0;
+const AOC_ERR_NULL: i32 = -1;
+const AOC_ERR_UTF8: i32 = -3;
+const AOC_ERR_PANIC: i32 = -4;
+const AOC_ERR_BUFFER: i32 = -5;
+
+type AocPluginSolve =
+    unsafe extern "C" fn(*const u8, usize, *const u8, usize, *mut u8, usize) -> i32;
+
+#[repr(C)]
+pub struct AocPluginSolver {
+    year: u32,
+    day: u32,
+    part: u32,
+    name: *const c_char,
+    solve: AocPluginSolve,
+}
+
+#[repr(C)]
+pub struct AocPlugin {
+    abi_version: u32,
+    name: *const c_char,
+    solvers: *const AocPluginSolver,
+    solver_count: usize,
+}
+
+// everything in here is immutable and points at static data
+unsafe impl Sync for AocPluginSolver {}
+unsafe impl Sync for AocPlugin {}
+
+/// the number of whole ms we can hold the button for and still beat `best`
+fn ways_to_win(time: u64, best: u64) -> u64 {
+    let distance = |hold: u64| hold as u128 * (time - hold) as u128;
+
+    // holding for half the race goes the furthest, so if that can't win
+    // nothing can. otherwise find the shortest hold that does
+    let (mut low, mut high) = (0, time / 2);
+    if distance(high) <= best as u128 {
+        return 0;
+    }
+    while low < high {
+        let mid = (low + high) / 2;
+        if distance(mid) > best as u128 {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    // the winning holds are symmetric around the middle of the race
+    time - 2 * low + 1
+}
+
+fn part1(binding: &str) -> u64 {
+    aoc2023_d06::parse(binding)
+        .unwrap()
+        .into_iter()
+        .map(|(time, best)| ways_to_win(time, best))
+        .product()
+}
+
+fn part2(binding: &str) -> u64 {
+    let (time, best) = aoc2023_d06::parse_joined(binding).unwrap();
+
+    ways_to_win(time, best)
+}
+
+/// the part of `AocPluginSolve` that's the same for both parts
+unsafe fn solve(
+    solver: fn(&str) -> u64,
+    input_ptr: *const u8,
+    len: usize,
+    out_buf: *mut u8,
+    out_len: usize,
+) -> i32 {
+    if input_ptr.is_null() || out_buf.is_null() {
+        return AOC_ERR_NULL;
+    }
+
+    let Ok(input) = std::str::from_utf8(slice::from_raw_parts(input_ptr, len)) else {
+        return AOC_ERR_UTF8;
+    };
+
+    // a panic can't be allowed to unwind into the runner
+    let Ok(answer) = panic::catch_unwind(|| solver(input).to_string()) else {
+        return AOC_ERR_PANIC;
+    };
+
+    if answer.len() + 1 > out_len {
+        return AOC_ERR_BUFFER;
+    }
+
+    let out = slice::from_raw_parts_mut(out_buf, out_len);
+    out[..answer.len()].copy_from_slice(answer.as_bytes());
+    out[answer.len()] = 0;
+
+    AOC_OK
+}
+
+unsafe extern "C" fn solve1(
+    input_ptr: *const u8,
+    len: usize,
+    _: *const u8,
+    _: usize,
+    out_buf: *mut u8,
+    out_len: usize,
+) -> i32 {
+    solve(part1, input_ptr, len, out_buf, out_len)
+}
+
+unsafe extern "C" fn solve2(
+    input_ptr: *const u8,
+    len: usize,
+    _: *const u8,
+    _: usize,
+    out_buf: *mut u8,
+    out_len: usize,
+) -> i32 {
+    solve(part2, input_ptr, len, out_buf, out_len)
+}
+
+static SOLVERS: [AocPluginSolver; 2] = [
+    AocPluginSolver {
+        year: 2023,
+        day: 6,
+        part: 1,
+        name: c"exact".as_ptr(),
+        solve: solve1,
+    },
+    AocPluginSolver {
+        year: 2023,
+        day: 6,
+        part: 2,
+        name: c"exact".as_ptr(),
+        solve: solve2,
+    },
+];
+
+static PLUGIN: AocPlugin = AocPlugin {
+    abi_version: AOC_PLUGIN_ABI_VERSION,
+    name: c"d06-exact".as_ptr(),
+    solvers: SOLVERS.as_ptr(),
+    solver_count: SOLVERS.len(),
+};
+
+#[no_mangle]
+pub extern "C" fn aoc_plugin_register(abi_version: u32) -> *const AocPlugin {
+    if abi_version != AOC_PLUGIN_ABI_VERSION {
+        return std::ptr::null();
+    }
+
+    &PLUGIN
+}
@@ -0,0 +1,48 @@
+//! the seeded generator behind `aoc gen`, on its own so benches and tests in
+//! the low-level crates can make inputs the same way without depending on
+//! the runner.
+
+/// a splitmix64 generator. it's tiny, but it's fast, has no dependencies, and
+/// its output won't change out from under us between crate versions
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// uniform in `lo..=hi`
+    pub fn range(&mut self, lo: u64, hi: u64) -> u64 {
+        let span = (hi - lo) as u128 + 1;
+        lo + ((self.next_u64() as u128 * span) >> 64) as u64
+    }
+
+    /// uniform in `lo..=hi`
+    pub fn irange(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + self.range(0, hi.abs_diff(lo)) as i64
+    }
+
+    /// true with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0, items.len() as u64 - 1) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.range(0, i as u64) as usize;
+            items.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use regex::Regex;
+use rng::Rng;
+use std::hint::black_box;
+
+/// something shaped like a day 5 almanac: lots of lines of three big numbers
+fn almanac() -> String {
+    let mut input = String::from("seeds: 79 14 55 13\n\nseed-to-soil map:\n");
+    let mut rng = Rng::new(0);
+    for _ in 0..5000 {
+        let mut nums = [0; 3];
+        for num in nums.iter_mut() {
+            *num = rng.range(0, 3_999_999_999);
+        }
+        input += &format!("{} {} {}\n", nums[0], nums[1], nums[2]);
+    }
+    input
+}
+
+fn bench(c: &mut Criterion) {
+    let input = almanac();
+    let num_re = Regex::new(r"(\d+)").unwrap();
+
+    let mut group = c.benchmark_group("almanac");
+
+    group.bench_function("scan", |b| {
+        b.iter(|| {
+            scan::ints::<i64>(black_box(&input))
+                .map(|x| x.unwrap())
+                .sum::<i64>()
+        })
+    });
+
+    group.bench_function("regex", |b| {
+        b.iter(|| {
+            num_re
+                .captures_iter(black_box(&input))
+                .map(|x| x[1].parse::<i64>().unwrap())
+                .sum::<i64>()
+        })
+    });
+
+    group.bench_function("split", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .split([' ', '\n'])
+                .filter_map(|x| x.parse::<i64>().ok())
+                .sum::<i64>()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,187 @@
+//! pulls integers out of puzzle input without allocating. anything that isn't
+//! a digit counts as a separator, so the same scanner works for
+//! `seeds: 79 14 55 13`, `Game 12: 3 blue, 4 red`, and `0 3 -6 9`.
+//!
+//! it's also home to [`ParseError`], which every day's parser uses to say
+//! what's wrong with input it can't make sense of, and [`LineReader`], for
+//! the days that can work through their input a line at a time.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+mod lines;
+
+pub use lines::{LineReader, StreamError};
+
+/// a number that didn't fit in the requested integer type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    /// byte range of the offending number, including any leading `-`
+    pub span: Range<usize>,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "integer at bytes {}..{} overflows",
+            self.span.start, self.span.end
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+impl Overflow {
+    /// pins the overflow to line `idx` (counting from 0) of the input
+    pub fn on_line(self, idx: usize) -> ParseError {
+        ParseError::new(idx, self.to_string())
+    }
+}
+
+/// puzzle input that isn't in the shape a day expects
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// line number the problem was found on, counting from 1
+    pub line: usize,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// `idx` is the line's index, counting from 0, as handed out by `enumerate`
+    pub fn new(idx: usize, reason: impl Into<String>) -> ParseError {
+        ParseError {
+            line: idx + 1,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// an integer type the scanner can produce
+pub trait Int: Copy {
+    /// whether a `-` directly in front of a number makes it negative. for
+    /// unsigned types, `-` is just another separator
+    const SIGNED: bool;
+    const ZERO: Self;
+
+    /// `self * 10 + digit`, or `self * 10 - digit` when building up a
+    /// negative number, returning `None` on overflow
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+}
+
+macro_rules! int {
+    ($signed:literal, $($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const SIGNED: bool = $signed;
+                const ZERO: Self = 0;
+
+                #[inline]
+                fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                    let shifted = self.checked_mul(10)?;
+                    // negatives are built downwards so that MIN doesn't overflow
+                    if negative {
+                        shifted.checked_sub(digit as $t)
+                    } else {
+                        shifted.checked_add(digit as $t)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+int!(false, u8, u16, u32, u64, u128, usize);
+int!(true, i8, i16, i32, i64, i128, isize);
+
+/// iterator over every integer in a string or byte slice, in order
+pub struct Ints<'a, T> {
+    bytes: &'a [u8],
+    pos: usize,
+    _int: PhantomData<T>,
+}
+
+/// scans `input` for integers of type `T`
+pub fn ints<T: Int>(input: &(impl AsRef<[u8]> + ?Sized)) -> Ints<'_, T> {
+    Ints {
+        bytes: input.as_ref(),
+        pos: 0,
+        _int: PhantomData,
+    }
+}
+
+/// collects every integer in `input`, failing on the first one that overflows
+pub fn all<T: Int>(input: &(impl AsRef<[u8]> + ?Sized)) -> Result<Vec<T>, Overflow> {
+    ints(input).collect()
+}
+
+impl<'a, T: Int> Ints<'a, T> {
+    /// yields the byte range of each integer alongside its value
+    pub fn spans(self) -> Spans<'a, T> {
+        Spans { ints: self }
+    }
+
+    fn next_span(&mut self) -> Option<(Range<usize>, Result<T, Overflow>)> {
+        let bytes = self.bytes;
+
+        while self.pos < bytes.len() && !bytes[self.pos].is_ascii_digit() {
+            self.pos += 1;
+        }
+        if self.pos == bytes.len() {
+            return None;
+        }
+
+        let mut start = self.pos;
+        // only a `-` that isn't stuck to the end of a previous word counts as a
+        // sign, so `3-5` is two positive numbers but `3 -5` isn't
+        let negative = T::SIGNED
+            && start > 0
+            && bytes[start - 1] == b'-'
+            && (start == 1 || !bytes[start - 2].is_ascii_alphanumeric());
+        if negative {
+            start -= 1;
+        }
+
+        let mut value = Some(T::ZERO);
+        while self.pos < bytes.len() && bytes[self.pos].is_ascii_digit() {
+            value = value.and_then(|value| value.push_digit(bytes[self.pos] - b'0', negative));
+            self.pos += 1;
+        }
+
+        let span = start..self.pos;
+        match value {
+            Some(value) => Some((span, Ok(value))),
+            None => Some((span.clone(), Err(Overflow { span }))),
+        }
+    }
+}
+
+impl<T: Int> Iterator for Ints<'_, T> {
+    type Item = Result<T, Overflow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_span().map(|(_, value)| value)
+    }
+}
+
+/// see [`Ints::spans`]
+pub struct Spans<'a, T> {
+    ints: Ints<'a, T>,
+}
+
+impl<T: Int> Iterator for Spans<'_, T> {
+    type Item = (Range<usize>, Result<T, Overflow>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ints.next_span()
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::ParseError;
+
+/// reads input one line at a time into the same buffer, so a day can work
+/// through a file far bigger than memory. it's not an `Iterator`, since each
+/// line borrows the buffer:
+///
+/// ```
+/// let mut lines = scan::LineReader::new("1 2\n3 4\n".as_bytes());
+/// while let Some((idx, line)) = lines.next_line().unwrap() {
+///     println!("{}: {}", idx, line);
+/// }
+/// ```
+pub struct LineReader<R> {
+    reader: R,
+    buf: String,
+    idx: usize,
+}
+
+impl<R: BufRead> LineReader<R> {
+    pub fn new(reader: R) -> LineReader<R> {
+        LineReader {
+            reader,
+            buf: String::new(),
+            idx: 0,
+        }
+    }
+
+    /// the next line and its index, counting from 0, or `None` once the input
+    /// runs out. the `\n` is stripped, the same as `split('\n')` would, but
+    /// unlike `split` there's no empty line after a trailing `\n`
+    pub fn next_line(&mut self) -> io::Result<Option<(usize, &str)>> {
+        self.buf.clear();
+        if self.reader.read_line(&mut self.buf)? == 0 {
+            return Ok(None);
+        }
+
+        let idx = self.idx;
+        self.idx += 1;
+        Ok(Some((
+            idx,
+            self.buf.strip_suffix('\n').unwrap_or(&self.buf),
+        )))
+    }
+}
+
+/// anything that can go wrong solving from a reader instead of a string
+#[derive(Debug)]
+pub enum StreamError {
+    /// includes input that isn't valid utf-8
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "{}", err),
+            StreamError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> StreamError {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> StreamError {
+        StreamError::Parse(err)
+    }
+}
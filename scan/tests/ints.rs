@@ -0,0 +1,77 @@
+//! where numbers start and stop, what makes one negative, and what happens
+//! when one doesn't fit
+
+use scan::{all, ints, Overflow};
+
+/// every number's byte range and value, failing on an overflow
+fn spans<T: scan::Int>(input: &str) -> Vec<(std::ops::Range<usize>, T)> {
+    ints::<T>(input)
+        .spans()
+        .map(|(span, value)| (span, value.unwrap()))
+        .collect()
+}
+
+#[test]
+fn signs() {
+    assert_eq!(all::<i32>("0 3 -6 9"), Ok(vec![0, 3, -6, 9]));
+    assert_eq!(all::<i32>("-5"), Ok(vec![-5]));
+    assert_eq!(all::<i32>("--5, -0"), Ok(vec![-5, 0]));
+
+    // a `-` stuck to the end of a word is a separator, so ranges and names
+    // read as positive
+    assert_eq!(all::<i32>("3-5"), Ok(vec![3, 5]));
+    assert_eq!(all::<i32>("seed-to-soil x-7 2-"), Ok(vec![7, 2]));
+
+    // and for unsigned types it's always just a separator
+    assert_eq!(all::<u32>("0 3 -6 9"), Ok(vec![0, 3, 6, 9]));
+    assert_eq!(spans::<u32>("-6"), [(1..2, 6)]);
+}
+
+#[test]
+fn extremes() {
+    assert_eq!(all::<i8>("-128 127"), Ok(vec![i8::MIN, i8::MAX]));
+    assert_eq!(all::<u8>("0 255"), Ok(vec![0, u8::MAX]));
+    assert_eq!(all::<i64>(&i64::MIN.to_string()), Ok(vec![i64::MIN]));
+    assert_eq!(all::<i128>(&i128::MIN.to_string()), Ok(vec![i128::MIN]));
+    assert_eq!(all::<u128>(&u128::MAX.to_string()), Ok(vec![u128::MAX]));
+
+    // leading zeros don't count towards overflowing
+    assert_eq!(all::<u8>("000000255"), Ok(vec![255]));
+}
+
+#[test]
+fn overflow() {
+    assert_eq!(all::<i8>("-129"), Err(Overflow { span: 0..4 }));
+    assert_eq!(all::<i8>("1 128"), Err(Overflow { span: 2..5 }));
+    assert_eq!(all::<u8>("256"), Err(Overflow { span: 0..3 }));
+    assert_eq!(
+        all::<i64>("x 9223372036854775808"),
+        Err(Overflow { span: 2..21 })
+    );
+
+    // the whole number is skipped, and scanning carries on after it
+    let found = ints::<u8>("300 5").collect::<Vec<_>>();
+    assert_eq!(found, [Err(Overflow { span: 0..3 }), Ok(5)]);
+
+    assert_eq!(
+        Overflow { span: 4..7 }.on_line(2).to_string(),
+        "line 3: integer at bytes 4..7 overflows"
+    );
+}
+
+#[test]
+fn adjacent_spans() {
+    assert_eq!(spans::<u32>("1,2"), [(0..1, 1), (2..3, 2)]);
+    assert_eq!(spans::<u32>("12ab34"), [(0..2, 12), (4..6, 34)]);
+    assert_eq!(spans::<i32>("7-8"), [(0..1, 7), (2..3, 8)]);
+    assert_eq!(spans::<i32>("7 -8"), [(0..1, 7), (2..4, -8)]);
+    assert_eq!(spans::<u32>("007"), [(0..3, 7)]);
+
+    // spans are bytes, not characters
+    assert_eq!(spans::<u32>("é12ü3"), [(2..4, 12), (6..7, 3)]);
+    // and bytes work as well as strings
+    assert_eq!(all::<u32>(b"4 5".as_slice()), Ok(vec![4, 5]));
+
+    assert!(spans::<u32>("").is_empty());
+    assert!(spans::<u32>("no numbers - here").is_empty());
+}
@@ -0,0 +1,62 @@
+//! how `LineReader` splits its input, compared with `split('\n')`
+
+use std::io::{self, ErrorKind};
+
+use scan::{LineReader, ParseError, StreamError};
+
+/// every line and its index, failing on a read error
+fn lines(input: &[u8]) -> Vec<(usize, String)> {
+    let mut reader = LineReader::new(input);
+    let mut lines = Vec::new();
+    while let Some((idx, line)) = reader.next_line().unwrap() {
+        lines.push((idx, line.to_string()));
+    }
+    lines
+}
+
+#[test]
+fn splitting() {
+    let expected = [(0, "1 2".to_string()), (1, "3 4".to_string())];
+    assert_eq!(lines(b"1 2\n3 4\n"), expected);
+    // the last line doesn't need its newline
+    assert_eq!(lines(b"1 2\n3 4"), expected);
+
+    // blank lines are still lines, and still counted
+    assert_eq!(
+        lines(b"a\n\n\nb\n"),
+        [
+            (0, "a".to_string()),
+            (1, String::new()),
+            (2, String::new()),
+            (3, "b".to_string())
+        ]
+    );
+    assert_eq!(lines(b"\n"), [(0, String::new())]);
+    assert!(lines(b"").is_empty());
+
+    // only `\n` is stripped, the same as `split` leaves a `\r`
+    assert_eq!(lines(b"x\r\n"), [(0, "x\r".to_string())]);
+}
+
+#[test]
+fn not_utf8() {
+    let mut reader = LineReader::new(b"ok\n\xff\nok\n".as_slice());
+    assert_eq!(reader.next_line().unwrap(), Some((0, "ok")));
+
+    let err = reader.next_line().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn errors() {
+    let err = StreamError::from(ParseError::new(4, "no colon"));
+    assert!(matches!(
+        &err,
+        StreamError::Parse(ParseError { line: 5, .. })
+    ));
+    assert_eq!(err.to_string(), "line 5: no colon");
+
+    let err = StreamError::from(io::Error::new(ErrorKind::UnexpectedEof, "cut short"));
+    assert!(matches!(err, StreamError::Io(_)));
+    assert_eq!(err.to_string(), "cut short");
+}
@@ -0,0 +1,70 @@
+use aoc2023_d03::Number;
+use crossterm::style::Color;
+use scan::ParseError;
+
+use crate::scene::{Paint, Scene};
+
+/// paints every digit of the number
+fn span(number: &Number, colour: Color) -> Vec<Paint> {
+    (number.start..number.end)
+        .map(|col| (number.row, col, colour))
+        .collect()
+}
+
+/// marks each number as a part (green) or not (red), then lights up every
+/// `*` that turns out to be a gear along with its two numbers
+pub fn scene(binding: &str) -> Result<Scene, ParseError> {
+    let grid = binding
+        .lines()
+        .map(|line| line.chars().collect::<Vec<char>>())
+        .collect::<Vec<Vec<char>>>();
+
+    let mut scene = Scene::new("d03", grid);
+
+    let mut parts = aoc2023_d03::part_numbers(binding)?;
+    parts.sort_by_key(|(number, _)| (number.row, number.start));
+
+    for (number, ok) in parts {
+        if ok {
+            scene.push(
+                span(&number, Color::Green),
+                format!("{} is a part number", number.value),
+            );
+        } else {
+            scene.push(
+                span(&number, Color::Red),
+                format!("{} does not touch a symbol", number.value),
+            );
+        }
+    }
+
+    for gear in aoc2023_d03::gears(binding)? {
+        let (row, col) = (gear.symbol.row, gear.symbol.col);
+        match gear.ratio {
+            Some(ratio) => {
+                let ratio = ratio.expect("two u32s always multiply into a u64");
+                let mut paints = vec![(row, col, Color::Yellow)];
+                paints.extend(span(&gear.numbers[0], Color::Yellow));
+                paints.extend(span(&gear.numbers[1], Color::Yellow));
+                scene.push(
+                    paints,
+                    format!(
+                        "gear at ({}, {}): {} * {} = {}",
+                        row, col, gear.numbers[0].value, gear.numbers[1].value, ratio
+                    ),
+                );
+            }
+            None => scene.push(
+                vec![(row, col, Color::DarkGrey)],
+                format!(
+                    "* at ({}, {}) touches {} numbers, not a gear",
+                    row,
+                    col,
+                    gear.numbers.len()
+                ),
+            ),
+        }
+    }
+
+    Ok(scene)
+}
@@ -0,0 +1,54 @@
+use aoc2023_d10::{pipe, Tile};
+use crossterm::style::Color;
+use scan::ParseError;
+
+use crate::scene::Scene;
+
+/// walks the loop from `S` one tile at a time, then plays back the `within`
+/// scan one row at a time
+pub fn scene(binding: &str) -> Result<Scene, ParseError> {
+    let walk = aoc2023_d10::walk(binding)?;
+    let grid = walk
+        .grid
+        .iter()
+        .map(|row| row.iter().map(|tile| pipe(tile)).collect::<Vec<char>>())
+        .collect::<Vec<Vec<char>>>();
+
+    let mut scene = Scene::new("d10", grid);
+
+    let (i, j) = walk.start;
+    scene.push(
+        vec![(i, j, Color::Magenta)],
+        format!("start at ({}, {})", i, j),
+    );
+
+    for (idx, (i, j)) in walk.path.iter().enumerate() {
+        let colour = if (*i, *j) == walk.start {
+            Color::Magenta
+        } else {
+            Color::Yellow
+        };
+        scene.push(
+            vec![(*i, *j, colour)],
+            format!("walk: step {} at ({}, {})", idx + 1, i, j),
+        );
+    }
+
+    for (idx, row) in aoc2023_d10::classify(&walk).iter().enumerate() {
+        let mut paints = Vec::new();
+        let mut inside = 0;
+        for (jdx, tile) in row.iter().enumerate() {
+            match tile {
+                Tile::Inside => {
+                    paints.push((idx, jdx, Color::Green));
+                    inside += 1;
+                }
+                Tile::Outside => paints.push((idx, jdx, Color::DarkGrey)),
+                Tile::Loop => {}
+            }
+        }
+        scene.push(paints, format!("scan: row {} has {} inside", idx, inside));
+    }
+
+    Ok(scene)
+}
@@ -0,0 +1,46 @@
+mod d03;
+mod d10;
+mod scene;
+mod view;
+
+const USAGE: &str = "usage: viz <d03|d10> [input file, defaults to input.txt]";
+
+fn main() {
+    let args = std::env::args().collect::<Vec<String>>();
+
+    let Some(day) = args.get(1) else {
+        eprintln!("{}", USAGE);
+        std::process::exit(1);
+    };
+    let path = args.get(2).map(|x| x.as_str()).unwrap_or("input.txt");
+
+    let binding = match std::fs::read_to_string(path) {
+        Ok(binding) => binding,
+        Err(err) => {
+            eprintln!("couldn't read {}: {}", path, err);
+            std::process::exit(1);
+        }
+    };
+
+    let scene = match day.as_str() {
+        "d03" | "3" => d03::scene(&binding),
+        "d10" | "10" => d10::scene(&binding),
+        _ => {
+            eprintln!("no visualizer for {}\n{}", day, USAGE);
+            std::process::exit(1);
+        }
+    };
+
+    let scene = match scene {
+        Ok(scene) => scene,
+        Err(err) => {
+            eprintln!("couldn't parse {}: {}", path, err);
+            std::process::exit(1);
+        }
+    };
+
+    if let Err(err) = view::run(&scene) {
+        eprintln!("terminal error: {}", err);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,98 @@
+use crossterm::style::Color;
+
+/// a single change to the grid: colour the tile at (row, col)
+pub type Paint = (usize, usize, Color);
+
+/// the colour of every tile, `None` for ones nothing has painted yet
+pub type Colours = Vec<Vec<Option<Color>>>;
+
+pub struct Step {
+    pub paints: Vec<Paint>,
+    /// what each tile in `paints` was before this step, for playing it
+    /// backwards
+    before: Vec<Option<Color>>,
+    pub caption: String,
+}
+
+/// everything the viewer needs to play back a day: the raw grid, and the
+/// steps that colour it in, in order
+pub struct Scene {
+    pub title: String,
+    pub grid: Vec<Vec<char>>,
+    pub steps: Vec<Step>,
+    /// the colours after every step so far, to fill in each one's `before`
+    last: Colours,
+}
+
+impl Scene {
+    pub fn new(title: &str, grid: Vec<Vec<char>>) -> Scene {
+        Scene {
+            title: title.to_string(),
+            last: blank(&grid),
+            grid,
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, paints: Vec<Paint>, caption: String) {
+        let before = paints
+            .iter()
+            .map(|&(row, col, colour)| self.last[row][col].replace(colour))
+            .collect();
+        self.steps.push(Step {
+            paints,
+            before,
+            caption,
+        });
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.len()
+    }
+}
+
+fn blank(grid: &[Vec<char>]) -> Colours {
+    grid.iter().map(|row| vec![None; row.len()]).collect()
+}
+
+/// the colours after some number of a scene's steps. moving it replays or
+/// undoes only the steps in between, so stepping through a scene doesn't
+/// mean replaying it from the start on every redraw
+pub struct Frame {
+    pos: usize,
+    colours: Colours,
+}
+
+impl Frame {
+    /// the grid before any step has been played
+    pub fn new(scene: &Scene) -> Frame {
+        Frame {
+            pos: 0,
+            colours: blank(&scene.grid),
+        }
+    }
+
+    /// moves to just after the first `pos` steps of `scene`
+    pub fn seek(&mut self, scene: &Scene, pos: usize) {
+        for step in &scene.steps[self.pos.min(pos)..pos] {
+            for &(row, col, colour) in &step.paints {
+                self.colours[row][col] = Some(colour);
+            }
+        }
+        for step in scene.steps[pos..self.pos.max(pos)].iter().rev() {
+            // undone last to first, in case a step paints a tile twice
+            for (&(row, col, _), &before) in step.paints.iter().zip(&step.before).rev() {
+                self.colours[row][col] = before;
+            }
+        }
+        self.pos = pos;
+    }
+
+    pub fn colours(&self) -> &Colours {
+        &self.colours
+    }
+}
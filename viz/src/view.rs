@@ -0,0 +1,261 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+use crossterm::{cursor, execute, queue, terminal};
+
+use crate::scene::{Frame, Scene};
+
+const TICK: Duration = Duration::from_millis(30);
+const HELP: &str =
+    "arrows/pgup/pgdn: scroll  ,/.: step  home/end: jump  space: pause  +/-: speed  q: quit";
+
+/// puts the terminal back the way we found it, even if we panic mid-draw
+struct Guard;
+
+impl Guard {
+    fn new() -> io::Result<Guard> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Guard)
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+struct Viewer<'a> {
+    scene: &'a Scene,
+    /// how many steps have been played
+    pos: usize,
+    /// the colours as of the last draw, caught up to `pos` on the next one
+    frame: Frame,
+    playing: bool,
+    /// steps played per tick
+    speed: usize,
+    /// top left tile of the viewport, as (row, col)
+    offset: (usize, usize),
+    /// size of the viewport in tiles, as (rows, cols)
+    size: (usize, usize),
+}
+
+impl Viewer<'_> {
+    fn resize(&mut self) -> io::Result<()> {
+        let (cols, rows) = terminal::size()?;
+        // leave the bottom two rows for the status and help lines
+        self.size = (
+            (rows as usize).saturating_sub(2).max(1),
+            (cols as usize).max(1),
+        );
+        self.scroll(0, 0);
+        Ok(())
+    }
+
+    /// moves the viewport, keeping it within the bounds of the grid
+    fn scroll(&mut self, rows: isize, cols: isize) {
+        let max_row = self.scene.height().saturating_sub(self.size.0);
+        let max_col = self.scene.width().saturating_sub(self.size.1);
+        self.offset.0 = self.offset.0.saturating_add_signed(rows).min(max_row);
+        self.offset.1 = self.offset.1.saturating_add_signed(cols).min(max_col);
+    }
+
+    /// scrolls just enough to keep the most recently played step on screen
+    fn follow(&mut self) {
+        if self.pos == 0 {
+            return;
+        }
+        let Some((row, col, _)) = self.scene.steps[self.pos - 1].paints.first() else {
+            return;
+        };
+
+        let (top, left) = self.offset;
+        let (rows, cols) = self.size;
+        if *row < top {
+            self.offset.0 = *row;
+        } else if *row >= top + rows {
+            self.offset.0 = row + 1 - rows;
+        }
+        if *col < left {
+            self.offset.1 = *col;
+        } else if *col >= left + cols {
+            self.offset.1 = col + 1 - cols;
+        }
+    }
+
+    fn step(&mut self, by: isize) {
+        self.pos = self
+            .pos
+            .saturating_add_signed(by)
+            .min(self.scene.steps.len());
+        self.follow();
+    }
+
+    /// returns false once the user asks to quit
+    fn handle(&mut self, key: KeyEvent) -> bool {
+        let page = self.size.0 as isize;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => {
+                if self.pos == self.scene.steps.len() {
+                    self.pos = 0;
+                }
+                self.playing = !self.playing;
+            }
+            KeyCode::Char('.') | KeyCode::Char('n') => {
+                self.playing = false;
+                self.step(1);
+            }
+            KeyCode::Char(',') | KeyCode::Char('p') => {
+                self.playing = false;
+                self.step(-1);
+            }
+            KeyCode::Home => {
+                self.playing = false;
+                self.pos = 0;
+            }
+            KeyCode::End => {
+                self.playing = false;
+                self.pos = self.scene.steps.len();
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => self.speed = (self.speed * 2).min(1 << 12),
+            KeyCode::Char('-') => self.speed = (self.speed / 2).max(1),
+            KeyCode::Up => self.scroll(-1, 0),
+            KeyCode::Down => self.scroll(1, 0),
+            KeyCode::Left => self.scroll(0, -1),
+            KeyCode::Right => self.scroll(0, 1),
+            KeyCode::PageUp => self.scroll(-page, 0),
+            KeyCode::PageDown => self.scroll(page, 0),
+            _ => {}
+        }
+        true
+    }
+
+    fn draw(&mut self, out: &mut impl Write) -> io::Result<()> {
+        self.frame.seek(self.scene, self.pos);
+        let colours = self.frame.colours();
+        let (top, left) = self.offset;
+        let (rows, cols) = self.size;
+
+        for screen_row in 0..rows {
+            let row = top + screen_row;
+            queue!(
+                out,
+                cursor::MoveTo(0, screen_row as u16),
+                terminal::Clear(terminal::ClearType::CurrentLine)
+            )?;
+            if row >= self.scene.height() {
+                continue;
+            }
+
+            let tiles = self.scene.grid[row].iter().zip(colours[row].iter());
+
+            let mut current = None;
+            for (tile, colour) in tiles.skip(left).take(cols) {
+                let colour = *colour;
+                if colour != current {
+                    match colour {
+                        Some(colour) => queue!(out, SetForegroundColor(colour))?,
+                        None => queue!(out, ResetColor)?,
+                    }
+                    current = colour;
+                }
+                queue!(out, Print(tile))?;
+            }
+            queue!(out, ResetColor)?;
+        }
+
+        let caption = match self.pos {
+            0 => "",
+            pos => self.scene.steps[pos - 1].caption.as_str(),
+        };
+        let status = format!(
+            "{} | step {}/{} | {} x{} | {}",
+            self.scene.title,
+            self.pos,
+            self.scene.steps.len(),
+            if self.playing { "playing" } else { "paused" },
+            self.speed,
+            caption
+        );
+
+        queue!(
+            out,
+            cursor::MoveTo(0, rows as u16),
+            terminal::Clear(terminal::ClearType::CurrentLine),
+            SetForegroundColor(Color::White),
+            Print(truncate(&status, cols)),
+            cursor::MoveTo(0, rows as u16 + 1),
+            terminal::Clear(terminal::ClearType::CurrentLine),
+            SetForegroundColor(Color::DarkGrey),
+            Print(truncate(HELP, cols)),
+            ResetColor
+        )?;
+
+        out.flush()
+    }
+}
+
+fn truncate(line: &str, width: usize) -> String {
+    line.chars().take(width).collect()
+}
+
+/// plays back `scene` in the terminal until the user quits
+pub fn run(scene: &Scene) -> io::Result<()> {
+    let _guard = Guard::new()?;
+    let mut out = io::BufWriter::new(io::stdout());
+
+    let mut viewer = Viewer {
+        scene,
+        pos: 0,
+        frame: Frame::new(scene),
+        playing: true,
+        speed: 1,
+        offset: (0, 0),
+        size: (1, 1),
+    };
+    viewer.resize()?;
+
+    let mut dirty = true;
+    loop {
+        if dirty {
+            viewer.draw(&mut out)?;
+            dirty = false;
+        }
+
+        let timeout = if viewer.playing {
+            TICK
+        } else {
+            Duration::from_millis(250)
+        };
+
+        if event::poll(timeout)? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    if !viewer.handle(key) {
+                        break;
+                    }
+                    dirty = true;
+                }
+                Event::Resize(_, _) => {
+                    viewer.resize()?;
+                    dirty = true;
+                }
+                _ => {}
+            }
+        } else if viewer.playing {
+            if viewer.pos < scene.steps.len() {
+                viewer.step(viewer.speed as isize);
+            } else {
+                viewer.playing = false;
+            }
+            dirty = true;
+        }
+    }
+
+    Ok(())
+}